@@ -0,0 +1,599 @@
+//! Procedural macros for [geode](https://docs.rs/geode).
+//!
+//! Use these through geode's `derive` feature.
+
+#![doc(html_root_url = "https://docs.rs/geode-derive/0.0.1")]
+#![warn(clippy::pedantic, missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Spacing, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::HashMap;
+use syn::{
+	ext::IdentExt,
+	parse::{Parse, ParseStream},
+	parse_macro_input,
+	spanned::Spanned,
+	Attribute, Data, DeriveInput, Error, Fields, GenericParam, Path, Result, Token,
+};
+
+/// Generic parameter names used by the generated implementations.
+const RESERVED: &[&str] = &[
+	"Acc", "E", "F", "H", "I", "L", "N", "R", "R0", "Rhs", "Target", "Tuple", "X",
+];
+
+/// Lifetime names (without `'`) used by the generated implementations.
+const RESERVED_LIFETIMES: &[&str] = &["list"];
+
+/// Generates a custom cons list from a struct with a single `head` field,
+/// like `geode::custom_list!` but with full generics support.
+///
+/// ```rust
+/// trait Widget {}
+///
+/// #[geode::derive::list]
+/// pub struct Widgets<'a, T: ?Sized + Widget>
+/// where
+///     T: 'a,
+/// {
+///     pub head: &'a T,
+/// }
+/// ```
+///
+/// This declares `Widgets<'a, T, R>` with an added `rest: R` field,
+/// an empty `WidgetsEnd` and a builder trait `WidgetsCons<'a, T>`.
+///
+/// # Arguments
+///
+/// - `end = Name` and `cons = Name` rename the end type and builder trait.
+/// - `wrapper = Name` additionally generates an opaque wrapper.
+/// - `no_cons`, `no_r_cons` and `sealed` limit the builder trait.
+///   `sealed` declares the seal in a private module named after the list, like `__sealed_widgets`.
+/// - `no_standard_traits` omits the `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash` and `Default` implementations.
+/// - `crate = path` sets the path to geode, `::geode` by default.
+///
+/// The generic parameter names `'list`, `Acc`, `E`, `F`, `H`, `I`, `L`, `N`, `R`, `R0`, `Rhs`, `Target`, `Tuple` and `X`
+/// are used by the generated implementations and can't be used on the list.
+#[proc_macro_attribute]
+pub fn list(args: TokenStream, input: TokenStream) -> TokenStream {
+	let list_args = parse_macro_input!(args as ListArgs);
+	let input = parse_macro_input!(input as DeriveInput);
+	implement_list(list_args, input)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+/// Implements `geode::Generic`, with the struct's field types as `Repr` list.
+///
+/// Use `#[geode(crate = path)]` if geode isn't available as `::geode`.
+///
+/// ```rust
+/// use geode::{Generic, List};
+///
+/// #[derive(Debug, PartialEq, Generic)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let repr: List![i32, i32] = Point { x: 1, y: 2 }.into_repr();
+/// assert_eq!(Point::from_repr(repr), Point { x: 1, y: 2 });
+/// ```
+#[proc_macro_derive(Generic, attributes(geode))]
+pub fn derive_generic(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	implement_generic(&input, false)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+/// Implements `geode::LabelledGeneric`, with the struct's fields as `Repr` list of `geode::Field`s.
+///
+/// Use `#[geode(crate = path)]` if geode isn't available as `::geode`.
+///
+/// ```rust
+/// use geode::LabelledGeneric;
+///
+/// #[derive(LabelledGeneric)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let repr = Point { x: 1, y: 2 }.into_labelled_repr();
+/// assert_eq!((repr.head.name, repr.head.value), ("x", 1));
+/// ```
+#[proc_macro_derive(LabelledGeneric, attributes(geode))]
+pub fn derive_labelled_generic(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	implement_generic(&input, true)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+/// Arguments of `#[geode(…)]` on a derive input.
+struct DeriveArgs {
+	krate: Path,
+}
+
+impl Parse for DeriveArgs {
+	fn parse(input: ParseStream) -> Result<Self> {
+		input.parse::<Token![crate]>()?;
+		input.parse::<Token![=]>()?;
+		let krate = input.parse()?;
+		if !input.is_empty() {
+			input.parse::<Token![,]>()?;
+		}
+		Ok(Self { krate })
+	}
+}
+
+fn implement_generic(input: &DeriveInput, labelled: bool) -> Result<TokenStream2> {
+	let mut krate = quote!(::geode);
+	for attr in &input.attrs {
+		if attr.path.is_ident("geode") {
+			krate = attr.parse_args::<DeriveArgs>()?.krate.into_token_stream();
+		}
+	}
+
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		Data::Enum(data) => return Err(Error::new(data.enum_token.span, "expected a struct")),
+		Data::Union(data) => return Err(Error::new(data.union_token.span, "expected a struct")),
+	};
+
+	let ident = &input.ident;
+	let bindings: Vec<_> = (0..fields.len())
+		.map(|i| Ident::new(&format!("field_{}", i), Span::mixed_site()))
+		.collect();
+	let (construct, destructure) = match fields {
+		Fields::Named(fields) => {
+			let field_names = fields.named.iter().map(|field| &field.ident);
+			let construct = quote!(#ident { #(#field_names: #bindings),* });
+			(construct.clone(), construct)
+		}
+		Fields::Unnamed(_) => {
+			let construct = quote!(#ident(#(#bindings),*));
+			(construct.clone(), construct)
+		}
+		Fields::Unit => (quote!(#ident), quote!(#ident)),
+	};
+
+	let mut repr = quote!(#krate::End);
+	let mut repr_pattern = quote!(#krate::End);
+	let mut into_repr = quote!(#krate::End);
+	for (i, (field, binding)) in fields.iter().zip(&bindings).enumerate().rev() {
+		let ty = &field.ty;
+		let name = field
+			.ident
+			.as_ref()
+			.map_or_else(|| i.to_string(), |ident| ident.unraw().to_string());
+		let (item_ty, item) = if labelled {
+			let label = label(&krate, &name, field.span())?;
+			(
+				quote!(#krate::Field<#label, #ty>),
+				quote!(#krate::Field::new(#name, #binding)),
+			)
+		} else {
+			(ty.to_token_stream(), binding.to_token_stream())
+		};
+		let value = if labelled {
+			quote!(#krate::Field { value: #binding, .. })
+		} else {
+			binding.to_token_stream()
+		};
+
+		repr = quote!(#krate::List<#item_ty, #repr>);
+		repr_pattern = quote!(#krate::List { head: #value, rest: #repr_pattern });
+		into_repr = quote!(#krate::List { head: #item, rest: #into_repr });
+	}
+
+	let (trait_, into, from) = if labelled {
+		(
+			quote!(#krate::LabelledGeneric),
+			quote!(into_labelled_repr),
+			quote!(from_labelled_repr),
+		)
+	} else {
+		(
+			quote!(#krate::Generic),
+			quote!(into_repr),
+			quote!(from_repr),
+		)
+	};
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics #trait_ for #ident #type_generics #where_clause {
+			type Repr = #repr;
+
+			fn #into(self) -> Self::Repr {
+				let #destructure = self;
+				#into_repr
+			}
+
+			fn #from(repr: Self::Repr) -> Self {
+				let #repr_pattern = repr;
+				#construct
+			}
+		}
+	})
+}
+
+/// Spells `name` as a list of `geode::labels` types.
+fn label(krate: &TokenStream2, name: &str, span: Span) -> Result<TokenStream2> {
+	let mut label = quote!(#krate::End);
+	for char in name.chars().rev() {
+		let ident = match char {
+			'a'..='z' | 'A'..='Z' => Ident::new(&char.to_string(), span),
+			'0'..='9' => Ident::new(&format!("_{}", char), span),
+			'_' => Ident::new("__", span),
+			_ => {
+				return Err(Error::new(
+					span,
+					format!(
+						"`{}` can't be used in a field label, only ASCII letters, digits and `_`",
+						char
+					),
+				))
+			}
+		};
+		label = quote!(#krate::List<#krate::labels::#ident, #label>);
+	}
+	Ok(label)
+}
+
+#[derive(Default)]
+struct ListArgs {
+	end: Option<Ident>,
+	cons: Option<Ident>,
+	wrapper: Option<Ident>,
+	krate: Option<Path>,
+	flags: Vec<Ident>,
+	list_flags: Vec<Ident>,
+}
+
+impl Parse for ListArgs {
+	fn parse(input: ParseStream) -> Result<Self> {
+		fn set<T: Spanned>(slot: &mut Option<T>, value: T) -> Result<()> {
+			if slot.is_some() {
+				return Err(Error::new(value.span(), "duplicate argument"));
+			}
+			*slot = Some(value);
+			Ok(())
+		}
+
+		let mut args = Self::default();
+		while !input.is_empty() {
+			if input.peek(Token![crate]) {
+				input.parse::<Token![crate]>()?;
+				input.parse::<Token![=]>()?;
+				set(&mut args.krate, input.parse()?)?;
+			} else {
+				let name: Ident = input.parse()?;
+				match name.to_string().as_str() {
+					"end" | "cons" | "wrapper" => {
+						input.parse::<Token![=]>()?;
+						let slot = match name.to_string().as_str() {
+							"end" => &mut args.end,
+							"cons" => &mut args.cons,
+							_ => &mut args.wrapper,
+						};
+						set(slot, input.parse()?)?;
+					}
+					"no_cons" | "no_r_cons" | "sealed" | "no_standard_traits" => {
+						if args.flags.contains(&name) || args.list_flags.contains(&name) {
+							return Err(Error::new(name.span(), "duplicate argument"));
+						}
+						if name == "no_standard_traits" {
+							args.list_flags.push(name);
+						} else {
+							args.flags.push(name);
+						}
+					}
+					_ => {
+						return Err(Error::new(
+							name.span(),
+							"expected `end`, `cons`, `wrapper`, `crate`, `no_cons`, `no_r_cons`, `sealed` or `no_standard_traits`",
+						))
+					}
+				}
+			}
+
+			if !input.is_empty() {
+				input.parse::<Token![,]>()?;
+			}
+		}
+		Ok(args)
+	}
+}
+
+fn implement_list(list_args: ListArgs, input: DeriveInput) -> Result<TokenStream2> {
+	let DeriveInput {
+		attrs,
+		vis,
+		ident,
+		generics,
+		data,
+	} = input;
+
+	let fields = match data {
+		Data::Struct(data) => match data.fields {
+			Fields::Named(fields) => fields.named,
+			fields => {
+				return Err(Error::new(
+					fields.span(),
+					"expected a struct with a single named `head` field",
+				))
+			}
+		},
+		Data::Enum(data) => return Err(Error::new(data.enum_token.span, "expected a struct")),
+		Data::Union(data) => return Err(Error::new(data.union_token.span, "expected a struct")),
+	};
+	let mut fields = fields.into_iter();
+	let head = fields
+		.next()
+		.ok_or_else(|| Error::new(ident.span(), "expected a `head` field"))?;
+	if let Some(extra) = fields.next() {
+		return Err(Error::new(
+			extra.span(),
+			"custom lists can only declare a `head` field, as `rest` is generated",
+		));
+	}
+	let head_ident = head.ident.expect("named field");
+	if head_ident != "head" {
+		return Err(Error::new(head_ident.span(), "expected a `head` field"));
+	}
+	if let Some(attr) = head.attrs.first() {
+		return Err(Error::new(
+			attr.span(),
+			"attributes on the `head` field aren't supported",
+		));
+	}
+	let head_vis = head.vis;
+	let item_ty = head.ty;
+
+	let krate = list_args
+		.krate
+		.map_or_else(|| quote!(::geode), ToTokens::into_token_stream);
+	let end = list_args
+		.end
+		.unwrap_or_else(|| format_ident!("{}End", ident));
+	let cons = list_args
+		.cons
+		.unwrap_or_else(|| format_ident!("{}Cons", ident));
+	let seal = format_ident!("__sealed_{}", ident.to_string().to_lowercase());
+	let flags = list_args.flags.iter().map(|flag| {
+		if flag == "sealed" {
+			quote!(#[#flag(#seal)])
+		} else {
+			quote!(#[#flag])
+		}
+	});
+	let list_flags = list_args.list_flags;
+
+	let generics = Generics::new(&generics)?;
+	let renamed = generics.renamed();
+	let (params, args, bounds) = (&generics.params, &generics.args, &generics.bounds);
+	let (args0, bounds0) = (&renamed.args, &renamed.bounds);
+	let params01 = generics.merged_params(&renamed);
+
+	let end_doc = format!("The end of a [`{}`].", ident);
+	let cons_doc = format!("Builder functionality for [`{}`] and [`{}`].", ident, end);
+	let wrapper = list_args.wrapper.map(|wrapper| {
+		let doc = format!("An opaque [`{}`].", ident);
+		quote!(#[doc = #doc] [#vis] struct #wrapper)
+	});
+
+	Ok(quote! {
+		#krate::custom_list!(@core
+			list: [#(#attrs)* #(#[#list_flags])* [#vis] struct #ident [#head_vis] head: #item_ty],
+			end: [#[doc = #end_doc] [#vis] struct #end],
+			cons: [#[doc = #cons_doc] #(#flags)*] [#vis] trait #cons,
+			wrapper: [#wrapper],
+			generics: [#params] [#args] [#bounds],
+			generics0: [#params01] [#args0] [#bounds #bounds0],
+		);
+	})
+}
+
+/// Comma-terminated generics token lists, in the shape `custom_list!(@core …)` expects.
+struct Generics {
+	/// Original parameter names (without `'` for lifetimes), to be renamed for the nested set.
+	names: Vec<(Ident, bool)>,
+	lifetime_params: TokenStream2,
+	other_params: TokenStream2,
+	params: TokenStream2,
+	args: TokenStream2,
+	bounds: TokenStream2,
+}
+
+impl Generics {
+	fn new(generics: &syn::Generics) -> Result<Self> {
+		let mut names = vec![];
+		let mut lifetime_params = TokenStream2::new();
+		let mut other_params = TokenStream2::new();
+		let mut args = TokenStream2::new();
+
+		for param in &generics.params {
+			let (ident, is_lifetime, reserved) = match param {
+				GenericParam::Lifetime(lifetime) => (
+					&lifetime.lifetime.ident,
+					true,
+					RESERVED_LIFETIMES
+						.iter()
+						.any(|r| lifetime.lifetime.ident == r),
+				),
+				GenericParam::Type(ty) => {
+					if let Some(default) = &ty.default {
+						return Err(default_error(default));
+					}
+					(&ty.ident, false, RESERVED.iter().any(|r| ty.ident == r))
+				}
+				GenericParam::Const(c) => {
+					if let Some(default) = &c.default {
+						return Err(default_error(default));
+					}
+					(&c.ident, false, RESERVED.iter().any(|r| c.ident == r))
+				}
+			};
+			if reserved {
+				return Err(Error::new(
+					param.span(),
+					"this name is used by the generated implementations, please rename this parameter",
+				));
+			}
+			names.push((ident.clone(), is_lifetime));
+
+			let (bare_attrs, param) = strip_attrs(param);
+			if let Some(attr) = bare_attrs.first() {
+				return Err(Error::new(
+					attr.span(),
+					"attributes on generic parameters aren't supported",
+				));
+			}
+			if is_lifetime {
+				param.to_tokens(&mut lifetime_params);
+			} else {
+				param.to_tokens(&mut other_params);
+			}
+			match &param {
+				GenericParam::Lifetime(lifetime) => lifetime.lifetime.to_tokens(&mut args),
+				GenericParam::Type(ty) => ty.ident.to_tokens(&mut args),
+				GenericParam::Const(c) => c.ident.to_tokens(&mut args),
+			}
+			if is_lifetime {
+				quote!(,).to_tokens(&mut lifetime_params);
+			} else {
+				quote!(,).to_tokens(&mut other_params);
+			}
+			quote!(,).to_tokens(&mut args);
+		}
+
+		let mut bounds = TokenStream2::new();
+		if let Some(where_clause) = &generics.where_clause {
+			for predicate in &where_clause.predicates {
+				quote!(#predicate,).to_tokens(&mut bounds);
+			}
+		}
+
+		let params = quote!(#lifetime_params #other_params);
+		Ok(Self {
+			names,
+			lifetime_params,
+			other_params,
+			params,
+			args,
+			bounds,
+		})
+	}
+
+	/// Returns a copy with each parameter renamed so that it doesn't collide with the originals.
+	fn renamed(&self) -> Self {
+		let taken = |name: &str| {
+			self.names.iter().any(|(ident, _)| ident == name)
+				|| RESERVED.contains(&name)
+				|| RESERVED_LIFETIMES.contains(&name)
+		};
+
+		let mut renames = HashMap::new();
+		for (ident, is_lifetime) in &self.names {
+			let mut name = format!("{}0", ident);
+			while taken(&name)
+				|| renames
+					.values()
+					.any(|(renamed, _): &(Ident, bool)| *renamed == name)
+			{
+				name.push('0');
+			}
+			renames.insert(
+				ident.to_string(),
+				(Ident::new(&name, ident.span()), *is_lifetime),
+			);
+		}
+
+		let rename = |tokens: &TokenStream2| rename(tokens.clone(), &renames);
+		Self {
+			names: vec![],
+			lifetime_params: rename(&self.lifetime_params),
+			other_params: rename(&self.other_params),
+			params: rename(&self.params),
+			args: rename(&self.args),
+			bounds: rename(&self.bounds),
+		}
+	}
+
+	/// Both parameter lists, with all lifetimes first.
+	fn merged_params(&self, renamed: &Self) -> TokenStream2 {
+		let (lifetimes, lifetimes0) = (&self.lifetime_params, &renamed.lifetime_params);
+		let (others, others0) = (&self.other_params, &renamed.other_params);
+		quote!(#lifetimes #lifetimes0 #others #others0)
+	}
+}
+
+fn default_error(default: &impl ToTokens) -> Error {
+	Error::new_spanned(
+		default,
+		"custom lists can't have defaulted generic parameters, as `R` follows them",
+	)
+}
+
+fn strip_attrs(param: &GenericParam) -> (Vec<Attribute>, GenericParam) {
+	let mut param = param.clone();
+	let attrs = match &mut param {
+		GenericParam::Lifetime(lifetime) => &mut lifetime.attrs,
+		GenericParam::Type(ty) => &mut ty.attrs,
+		GenericParam::Const(c) => &mut c.attrs,
+	};
+	(std::mem::take(attrs), param)
+}
+
+/// Replaces generic parameter names in `tokens`.
+///
+/// Lifetimes are matched as `'` followed by an identifier, other parameters as plain identifiers.
+/// Identifiers after `::` or `.` continue a path or expression and are left alone,
+/// as are associated type names in bindings like `Item = T`.
+fn rename(tokens: TokenStream2, substitutions: &HashMap<String, (Ident, bool)>) -> TokenStream2 {
+	let tokens: Vec<_> = tokens.into_iter().collect();
+	let punct = |i: usize| match tokens.get(i) {
+		Some(TokenTree::Punct(punct)) => Some((punct.as_char(), punct.spacing())),
+		_ => None,
+	};
+
+	let mut output = Vec::with_capacity(tokens.len());
+	for (i, token) in tokens.iter().enumerate() {
+		let token = match token {
+			TokenTree::Group(group) => {
+				let mut renamed = proc_macro2::Group::new(
+					group.delimiter(),
+					rename(group.stream(), substitutions),
+				);
+				renamed.set_span(group.span());
+				TokenTree::Group(renamed)
+			}
+			TokenTree::Ident(ident) => {
+				let previous = i.checked_sub(1).and_then(punct);
+				let after_apostrophe = matches!(previous, Some(('\'', _)));
+				let after_path_or_dot = matches!(previous, Some(('.', _)))
+					|| (matches!(previous, Some((':', _)))
+						&& matches!(
+							i.checked_sub(2).and_then(punct),
+							Some((':', Spacing::Joint))
+						));
+				let binding = matches!(punct(i + 1), Some(('=', Spacing::Alone)));
+				match substitutions.get(&ident.to_string()) {
+					Some((renamed, is_lifetime))
+						if *is_lifetime == after_apostrophe && !after_path_or_dot && !binding =>
+					{
+						let mut renamed = renamed.clone();
+						renamed.set_span(ident.span());
+						TokenTree::Ident(renamed)
+					}
+					_ => TokenTree::Ident(ident.clone()),
+				}
+			}
+			token => token.clone(),
+		};
+		output.push(token);
+	}
+	output.into_iter().collect()
+}
@@ -0,0 +1,251 @@
+//! A heterogeneous sum type, the counterpart of [`List`](struct@crate::List).
+//!
+//! A [`Coproduct`] holds exactly one value of the types it's made of.
+//! Its type can be named with [`Coproduct!`](crate::Coproduct!).
+
+use crate::{End, Here, List, There};
+
+/// Either a value of type `H` or one of the rest coproduct `T`.
+///
+/// Use [`Coproduct::inject`] to construct one without spelling out the nesting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Coproduct<H, T> {
+	/// The value is an `H`.
+	Inl(H),
+	/// The value is in the rest coproduct.
+	Inr(T),
+}
+
+/// The uninhabited end of a [`Coproduct`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CoproductEnd {}
+
+/// Construction of a coproduct from one of its types.
+///
+/// `I` is a type-level index ([`Here`] or [`There`]) that's normally inferred.
+pub trait Injector<T, I> {
+	/// Wraps `value` into the matching variant.
+	fn inject(value: T) -> Self;
+}
+
+impl<H, T> Injector<H, Here> for Coproduct<H, T> {
+	fn inject(value: H) -> Self {
+		Coproduct::Inl(value)
+	}
+}
+
+impl<H, T: Injector<X, I>, X, I> Injector<X, There<I>> for Coproduct<H, T> {
+	fn inject(value: X) -> Self {
+		Coproduct::Inr(T::inject(value))
+	}
+}
+
+/// Type-indexed access to a coproduct's value.
+///
+/// `I` is a type-level index ([`Here`] or [`There`]) that's normally inferred.
+pub trait Getter<T, I> {
+	/// Borrows the value, if it's a `T`.
+	fn get(&self) -> Option<&T>;
+
+	/// Mutably borrows the value, if it's a `T`.
+	fn get_mut(&mut self) -> Option<&mut T>;
+}
+
+impl<H, T> Getter<H, Here> for Coproduct<H, T> {
+	fn get(&self) -> Option<&H> {
+		match self {
+			Coproduct::Inl(head) => Some(head),
+			Coproduct::Inr(_) => None,
+		}
+	}
+
+	fn get_mut(&mut self) -> Option<&mut H> {
+		match self {
+			Coproduct::Inl(head) => Some(head),
+			Coproduct::Inr(_) => None,
+		}
+	}
+}
+
+impl<H, T: Getter<X, I>, X, I> Getter<X, There<I>> for Coproduct<H, T> {
+	fn get(&self) -> Option<&X> {
+		match self {
+			Coproduct::Inl(_) => None,
+			Coproduct::Inr(rest) => rest.get(),
+		}
+	}
+
+	fn get_mut(&mut self) -> Option<&mut X> {
+		match self {
+			Coproduct::Inl(_) => None,
+			Coproduct::Inr(rest) => rest.get_mut(),
+		}
+	}
+}
+
+/// Extraction of one of a coproduct's types.
+///
+/// `I` is a type-level index ([`Here`] or [`There`]) that's normally inferred.
+pub trait Uninjector<T, I> {
+	/// The coproduct of the remaining types.
+	type Remainder;
+
+	/// Returns the value if it's a `T`, or otherwise the same value as [`Uninjector::Remainder`].
+	///
+	/// # Errors
+	///
+	/// Iff the value isn't a `T`.
+	fn uninject(self) -> Result<T, Self::Remainder>;
+}
+
+impl<H, T> Uninjector<H, Here> for Coproduct<H, T> {
+	type Remainder = T;
+
+	fn uninject(self) -> Result<H, T> {
+		match self {
+			Coproduct::Inl(head) => Ok(head),
+			Coproduct::Inr(rest) => Err(rest),
+		}
+	}
+}
+
+impl<H, T: Uninjector<X, I>, X, I> Uninjector<X, There<I>> for Coproduct<H, T> {
+	type Remainder = Coproduct<H, T::Remainder>;
+
+	fn uninject(self) -> Result<X, Self::Remainder> {
+		match self {
+			Coproduct::Inl(head) => Err(Coproduct::Inl(head)),
+			Coproduct::Inr(rest) => rest.uninject().map_err(Coproduct::Inr),
+		}
+	}
+}
+
+/// Conversion into a coproduct of a superset of types.
+///
+/// `Indices` is a [`List`](struct@List) of the types' indices in `Target`, which is normally inferred.
+pub trait Embedder<Target, Indices> {
+	/// Moves the value into the matching variant of `Target`.
+	fn embed(self) -> Target;
+}
+
+impl<Target> Embedder<Target, End> for CoproductEnd {
+	fn embed(self) -> Target {
+		match self {}
+	}
+}
+
+impl<H, T: Embedder<Target, IRest>, Target: Injector<H, IHead>, IHead, IRest>
+	Embedder<Target, List<IHead, IRest>> for Coproduct<H, T>
+{
+	fn embed(self) -> Target {
+		match self {
+			Coproduct::Inl(head) => Target::inject(head),
+			Coproduct::Inr(rest) => rest.embed(),
+		}
+	}
+}
+
+/// Exhaustive handling of a coproduct's value.
+///
+/// `Handlers` is a [`List`](struct@List) of [`FnOnce`] closures, one per type in order,
+/// that all return `Output`.
+pub trait Fold<Handlers, Output> {
+	/// Calls the handler matching the value's type.
+	fn fold(self, handlers: Handlers) -> Output;
+}
+
+impl<Output> Fold<End, Output> for CoproductEnd {
+	fn fold(self, _: End) -> Output {
+		match self {}
+	}
+}
+
+impl<H, T: Fold<R, Output>, F: FnOnce(H) -> Output, R, Output> Fold<List<F, R>, Output>
+	for Coproduct<H, T>
+{
+	fn fold(self, handlers: List<F, R>) -> Output {
+		match self {
+			Coproduct::Inl(head) => (handlers.head)(head),
+			Coproduct::Inr(rest) => rest.fold(handlers.rest),
+		}
+	}
+}
+
+impl<H, T> Coproduct<H, T> {
+	/// Wraps `value` into the matching variant.
+	///
+	/// Its index `I` is inferred, see [`Injector`].
+	#[must_use]
+	pub fn inject<X, I>(value: X) -> Self
+	where
+		Self: Injector<X, I>,
+	{
+		Injector::inject(value)
+	}
+
+	/// Borrows the value, if it's an `X`.
+	///
+	/// Its index `I` is inferred, see [`Getter`].
+	#[must_use]
+	pub fn get<X, I>(&self) -> Option<&X>
+	where
+		Self: Getter<X, I>,
+	{
+		Getter::get(self)
+	}
+
+	/// Mutably borrows the value, if it's an `X`.
+	///
+	/// Its index `I` is inferred, see [`Getter`].
+	#[must_use]
+	pub fn get_mut<X, I>(&mut self) -> Option<&mut X>
+	where
+		Self: Getter<X, I>,
+	{
+		Getter::get_mut(self)
+	}
+
+	/// Returns the value, if it's an `X`.
+	///
+	/// Its index `I` is inferred, see [`Uninjector`].
+	#[must_use]
+	pub fn take<X, I>(self) -> Option<X>
+	where
+		Self: Uninjector<X, I>,
+	{
+		Uninjector::uninject(self).ok()
+	}
+
+	/// Returns the value if it's an `X`, or otherwise the same value as coproduct of the remaining types.
+	///
+	/// Its index `I` is inferred, see [`Uninjector`].
+	///
+	/// # Errors
+	///
+	/// Iff the value isn't an `X`.
+	pub fn uninject<X, I>(self) -> Result<X, <Self as Uninjector<X, I>>::Remainder>
+	where
+		Self: Uninjector<X, I>,
+	{
+		Uninjector::uninject(self)
+	}
+
+	/// Moves the value into the matching variant of `Target`.
+	///
+	/// The indices `I` are inferred, see [`Embedder`].
+	#[must_use]
+	pub fn embed<Target, I>(self) -> Target
+	where
+		Self: Embedder<Target, I>,
+	{
+		Embedder::embed(self)
+	}
+
+	/// Calls the handler matching the value's type, see [`Fold`].
+	pub fn fold<Handlers, Output>(self, handlers: Handlers) -> Output
+	where
+		Self: Fold<Handlers, Output>,
+	{
+		Fold::fold(self, handlers)
+	}
+}
@@ -1,29 +1,57 @@
 //! Concrete iterator types.
 
-use crate::{DynIteratee, DynIterateeMut, Iteratee, IterateeMut};
+use crate::{Cursor, CursorMut, DynIteratee, DynIterateeMut, Iteratee, IterateeMut};
 use core::{
 	fmt::{self, Debug, Formatter},
 	marker::PhantomData,
-	mem,
+	mem, ptr,
 };
 use pretty_type_name::pretty_type_name;
 
 /// A dynamic dispatch iterator.
-#[derive(Clone)]
+///
+/// [`DoubleEndedIterator::next_back`] goes through [`Iteratee::init_last`],
+/// which walks the remaining sequence from the front by default.
+/// Iterating a whole sequence from the back then takes quadratic time.
 pub struct Iter<'a, I: DynIteratee>
 where
 	I::Item: 'a,
 {
-	iteratee: I,
+	front: Front<I>,
+	len: usize,
 	_phantom: PhantomData<&'a ()>,
 }
 
+enum Front<I: DynIteratee> {
+	Iteratee(I),
+	/// Only reached by downgrading an [`IterMut`] that may have lent out items from its back.
+	Cursor(*const (), Cursor<I::Item>),
+}
+
+// Sound since `Iter` only ever shares what `I` shares.
+unsafe impl<'a, I: DynIteratee + Send> Send for Iter<'a, I> where I::Item: 'a {}
+unsafe impl<'a, I: DynIteratee + Sync> Sync for Iter<'a, I> where I::Item: 'a {}
+
 impl<I: DynIteratee> Iter<'_, I> {
 	/// Creates a new instance of [`Iter`] targeting the given [`Iteratee`].
 	#[must_use]
 	pub fn new(iteratee: I) -> Self {
 		Self {
-			iteratee,
+			len: iteratee.as_ref().size_hint().0,
+			front: Front::Iteratee(iteratee),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<I: DynIteratee> Clone for Iter<'_, I> {
+	fn clone(&self) -> Self {
+		Self {
+			front: match &self.front {
+				Front::Iteratee(iteratee) => Front::Iteratee(*iteratee),
+				Front::Cursor(this, cursor) => Front::Cursor(*this, *cursor),
+			},
+			len: self.len,
 			_phantom: PhantomData,
 		}
 	}
@@ -32,20 +60,42 @@ impl<I: DynIteratee> Iter<'_, I> {
 /// A mutating dynamic dispatch iterator.
 ///
 /// Note that this type is downgradeable into a [`Clone`] [`Iter`] via [`From`]/[`Into`] conversion.
+///
+/// [`DoubleEndedIterator::next_back`] requires an [`IterateeMut::cursor_mut`] while more than one item is left,
+/// and otherwise panics. Like [`Iter`]'s, it walks the remaining sequence from the front.
 pub struct IterMut<'a, I: DynIterateeMut>
 where
 	I::Item: 'a,
 {
-	iteratee: I,
-	_phantom: PhantomData<&'a ()>,
+	front: FrontMut<I>,
+	len: usize,
+	_phantom: PhantomData<&'a mut ()>,
 }
 
+enum FrontMut<I: DynIterateeMut> {
+	IterateeMut(I),
+	Cursor(*mut (), CursorMut<I::Item>),
+}
+
+// Sound since `IterMut` only ever lends out what `I` lends out.
+unsafe impl<'a, I: DynIterateeMut + Send> Send for IterMut<'a, I> where I::Item: 'a {}
+unsafe impl<'a, I: DynIterateeMut + Sync> Sync for IterMut<'a, I> where I::Item: 'a {}
+
 impl<I: DynIterateeMut> IterMut<'_, I> {
 	/// Creates a new instance of [`IterMut`] targeting the given [`IterateeMut`].
 	#[must_use]
-	pub fn new(iteratee: I) -> Self {
+	pub fn new(mut iteratee: I) -> Self {
+		let len = iteratee.as_ref().size_hint().0;
+		let front = match iteratee.as_ref().cursor_mut() {
+			Some(cursor) => FrontMut::Cursor(
+				(iteratee.as_mut() as *mut dyn IterateeMut<I::Item>).cast(),
+				cursor,
+			),
+			None => FrontMut::IterateeMut(iteratee),
+		};
 		Self {
-			iteratee,
+			front,
+			len,
 			_phantom: PhantomData,
 		}
 	}
@@ -53,17 +103,33 @@ impl<I: DynIterateeMut> IterMut<'_, I> {
 	/// Creates a new [`IterMut`] advancing independently of this one.
 	#[must_use]
 	pub fn fork(&mut self) -> IterMut<'_, I> {
-		IterMut::new(unsafe { (&mut self.iteratee as *mut I).read() })
+		IterMut {
+			front: match &mut self.front {
+				FrontMut::IterateeMut(iteratee) => {
+					FrontMut::IterateeMut(unsafe { (iteratee as *mut I).read() })
+				}
+				FrontMut::Cursor(this, cursor) => FrontMut::Cursor(*this, *cursor),
+			},
+			len: self.len,
+			_phantom: PhantomData,
+		}
 	}
 
 	/// Creates an [`Iter`] advancing independently of this one.
 	#[must_use]
-	pub fn fork_shared(&self) -> Iter<I::DynIteratee> {
-		Iter::new(unsafe {
-			(&self.iteratee.as_ref().as_iteratee() as *const &dyn Iteratee<I::Item>)
-				.cast::<I::DynIteratee>()
-				.read()
-		})
+	pub fn fork_shared(&self) -> Iter<'_, I::DynIteratee> {
+		Iter {
+			front: match &self.front {
+				FrontMut::IterateeMut(iteratee) => Front::Iteratee(unsafe {
+					(&iteratee.as_ref().as_iteratee() as *const &dyn Iteratee<I::Item>)
+						.cast::<I::DynIteratee>()
+						.read()
+				}),
+				FrontMut::Cursor(this, cursor) => Front::Cursor(*this as *const (), cursor.cursor),
+			},
+			len: self.len,
+			_phantom: PhantomData,
+		}
 	}
 }
 
@@ -71,46 +137,171 @@ impl<'a, I: DynIteratee> Iterator for Iter<'a, I> {
 	type Item = &'a I::Item;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let (head, rest) = self.iteratee.as_ref().head_rest();
-		unsafe {
-			let head = mem::transmute(head);
-			self.iteratee = (&rest as *const &dyn Iteratee<I::Item>).cast::<I>().read();
-			head
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+
+		match &mut self.front {
+			Front::Iteratee(iteratee) => {
+				let (head, rest) = iteratee.as_ref().head_rest();
+				unsafe {
+					//SAFETY: `head` and `rest` are shared for as long as `iteratee`'s target, which is `'a`.
+					let head = mem::transmute::<Option<&I::Item>, Option<&'a I::Item>>(head);
+					*iteratee = ptr::addr_of!(rest).cast::<I>().read();
+					head
+				}
+			}
+			Front::Cursor(this, cursor) => unsafe {
+				//SAFETY:
+				// `this` and `cursor` always belong together and point to the remaining sequence,
+				// which is shared for `'a`.
+				let (head, rest, rest_cursor) = (cursor.head_rest)(*this);
+				*this = rest;
+				*cursor = rest_cursor;
+				head.map(|head| &*head)
+			},
 		}
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<'a, I: DynIteratee> DoubleEndedIterator for Iter<'a, I> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len == 0 {
+			return None;
+		}
+
+		let last = match &self.front {
+			Front::Iteratee(iteratee) => unsafe {
+				//SAFETY: See `next`.
+				mem::transmute::<Option<&I::Item>, Option<&'a I::Item>>(
+					iteratee.as_ref().init_last(self.len),
+				)
+			},
+			Front::Cursor(this, cursor) => unsafe {
+				//SAFETY: See `next`.
+				(cursor.init_last)(*this, self.len).map(|last| &*last)
+			},
+		};
+		self.len -= 1;
+		last
+	}
 }
 
 impl<'a, I: DynIterateeMut> Iterator for IterMut<'a, I> {
 	type Item = &'a mut I::Item;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let iteratee = &mut self.iteratee as *mut I;
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+
+		match &mut self.front {
+			FrontMut::IterateeMut(iteratee) => {
+				let iteratee = iteratee as *mut I;
+				unsafe {
+					//SAFETY:
+					// `IterateeMut::head_rest_mut` may not leak references if it panics, and must still be in a memory-safe state too.
+					// As such, in that case, the previous mutable reference isn't borrowed anymore and can be left in place.
+					//
+					// Iff `head_rest_mut` returns, the target of `iteratee` may be borrowed through `head` for `'a`,
+					// but we can replace it with `rest` whose target is guaranteed to be disjoint (as far as pointer restrictions go).
+					let mut iteratee_ = iteratee.read();
+					let (head, mut rest) = iteratee_.as_mut().head_rest_mut();
+					let head =
+						mem::transmute::<Option<&mut I::Item>, Option<&'a mut I::Item>>(head);
+					iteratee.write(ptr::addr_of_mut!(rest).cast::<I>().read());
+					head
+				}
+			}
+			FrontMut::Cursor(this, cursor) => unsafe {
+				//SAFETY:
+				// `this` and `cursor` always belong together and point to the remaining sequence,
+				// which is exclusively borrowed for `'a`.
+				//
+				// The cursor functions only create a reference to the returned item, which then isn't part of the remaining sequence anymore.
+				// Items previously returned from either end are therefore never reborrowed.
+				let (head, rest, rest_cursor) = (cursor.head_rest)(*this);
+				debug_assert!(
+					head.is_some(),
+					"`head_rest` disagrees with the exact length"
+				);
+				*this = rest;
+				*cursor = rest_cursor;
+				head.map(|head| &mut *head)
+			},
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+}
+
+impl<I: DynIterateeMut> DoubleEndedIterator for IterMut<'_, I> {
+	/// # Panics
+	///
+	/// Iff more than one item is left and the [`IterateeMut`] has no [`IterateeMut::cursor_mut`].
+	///
+	/// Reaching the back through [`IterateeMut::head_rest_mut`] would reborrow the front,
+	/// which then aliases the returned item.
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (this, cursor) = match &self.front {
+			FrontMut::Cursor(this, cursor) => (*this, *cursor),
+			FrontMut::IterateeMut(_) if self.len <= 1 => return self.next(),
+			FrontMut::IterateeMut(_) => {
+				panic!("`IterMut` can only iterate from the back of `IterateeMut`s with a `cursor_mut`")
+			}
+		};
+
 		unsafe {
-			//SAFETY:
-			// `IterateeMut::head_rest_mut` may not leak references if it panics, and must still be in a memory-safe state too.
-			// As such, in that case, the previous mutable reference isn't borrowed anymore and can be left in place.
-			//
-			// Iff `head_rest_mut` returns, the target of `self.iteratee` may be borrowed through head for longer than `'_`,
-			// but we can replace it with `rest` whose target is guaranteed to be disjoint (as far as pointer restrictions go).
-			//
-			// TODO: Safety notes on the transmutes below.
-			let mut iteratee_ = iteratee.read();
-			let (head, mut rest) = iteratee_.as_mut().head_rest_mut();
-			let head = mem::transmute(head);
-			iteratee.write(
-				(&mut rest as *mut &mut dyn IterateeMut<I::Item>)
-					.cast::<I>()
-					.read(),
+			//SAFETY: See `next`.
+			let last = (cursor.init_last)(this, self.len);
+			debug_assert_eq!(
+				last.is_some(),
+				self.len > 0,
+				"`init_last` disagrees with the exact length"
 			);
-			head
+			let last = last?;
+
+			// The front is only allowed to reach `last` once it's the only item left.
+			// Zero-sized items may share their address, so they can't be told apart here.
+			// This check only runs with debug assertions, as it walks the sequence once more.
+			if cfg!(debug_assertions) && self.len > 1 && mem::size_of_val(&*last) > 0 {
+				let (head, _, _) = (cursor.head_rest)(this);
+				debug_assert_ne!(
+					head.map(<*mut _>::cast::<()>),
+					Some(last.cast::<()>()),
+					"the front and back of `IterMut` crossed"
+				);
+			}
+
+			self.len -= 1;
+			Some(&mut *last)
 		}
 	}
 }
 
+impl<I: DynIteratee> ExactSizeIterator for Iter<'_, I> {}
+
+impl<I: DynIterateeMut> ExactSizeIterator for IterMut<'_, I> {}
+
 /// Downgrades an [`IterMut`] into a ([`Clone`]) [`Iter`].
 impl<'a, I: DynIterateeMut> From<IterMut<'a, I>> for Iter<'a, I::DynIteratee> {
-	fn from(iter_mut: IterMut<I>) -> Self {
-		Self::new(iter_mut.iteratee.into_dyn_iteratee())
+	fn from(iter_mut: IterMut<'a, I>) -> Self {
+		Self {
+			front: match iter_mut.front {
+				FrontMut::IterateeMut(iteratee) => Front::Iteratee(iteratee.into_dyn_iteratee()),
+				FrontMut::Cursor(this, cursor) => Front::Cursor(this as *const (), cursor.cursor),
+			},
+			len: iter_mut.len,
+			_phantom: PhantomData,
+		}
 	}
 }
 
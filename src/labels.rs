@@ -0,0 +1,25 @@
+//! Type-level characters for [`Field`](crate::Field) names.
+//!
+//! A name is a [`List`](struct@crate::List) of these, one per character.
+//! Digits are spelled with a leading underscore (`_0`) and the underscore itself as `__`.
+
+macro_rules! labels {
+	($($name:ident => $char:literal),*$(,)?) => {$(
+		#[doc = concat!("The character `", $char, "`.")]
+		#[allow(non_camel_case_types)]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub enum $name {}
+	)*};
+}
+
+labels! {
+	a => "a", b => "b", c => "c", d => "d", e => "e", f => "f", g => "g", h => "h", i => "i",
+	j => "j", k => "k", l => "l", m => "m", n => "n", o => "o", p => "p", q => "q", r => "r",
+	s => "s", t => "t", u => "u", v => "v", w => "w", x => "x", y => "y", z => "z",
+	A => "A", B => "B", C => "C", D => "D", E => "E", F => "F", G => "G", H => "H", I => "I",
+	J => "J", K => "K", L => "L", M => "M", N => "N", O => "O", P => "P", Q => "Q", R => "R",
+	S => "S", T => "T", U => "U", V => "V", W => "W", X => "X", Y => "Y", Z => "Z",
+	_0 => "0", _1 => "1", _2 => "2", _3 => "3", _4 => "4",
+	_5 => "5", _6 => "6", _7 => "7", _8 => "8", _9 => "9",
+	__ => "_",
+}
@@ -11,22 +11,56 @@
 #[doc = include_str!("../README.md")]
 mod readme {}
 
+pub mod coproduct;
 pub mod iterators;
+pub mod labels;
 
-//TODO: Macro to generate a custom list type with optional Cons and RCons implementations.
-//TODO: Macro to privately implement iteration.
+#[cfg(feature = "derive")]
+pub use geode_derive::{Generic, LabelledGeneric};
+
+/// Attribute macros, enabled by the `derive` feature.
+///
+/// These are in a module because [`list!`] already occupies `geode::list`.
+#[cfg(feature = "derive")]
+pub mod derive {
+	pub use geode_derive::list;
+}
 
 /// A dynamic dispatch iteration target.
 ///
 /// # Safety
 ///
-/// For each marker trait that `Self` implements, the target of the [`dyn IterateeMut<T>`](`IterateeMut`) [reference](https://doc.rust-lang.org/stable/core/primitive.reference.html) returned from [`IterateeMut::head_rest_mut`] must implement it too.
+/// For each marker trait that `Self` implements, the target of the [`dyn Iteratee<T>`](`Iteratee`) [reference](https://doc.rust-lang.org/stable/core/primitive.reference.html) returned from [`Iteratee::head_rest`] must implement it too.
+///
+/// [`Iteratee::size_hint`] must return the exact length of the sequence as both bounds.
+/// [`iterators::IterMut`] relies on it to tell where its front and back meet.
 pub unsafe trait Iteratee<T: ?Sized> {
 	/// Returns the first item reference, if available, and "rest of the sequence"-iteratee.
 	fn head_rest(&self) -> (Option<&T>, &dyn Iteratee<T>);
 
 	/// Used to implement [`Iterator::size_hint`] on [`iterators::Iter`] and [`iterators::IterMut`].
+	///
+	/// As those implement [`ExactSizeIterator`], the returned bounds must be equal to the length of the sequence.
 	fn size_hint(&self) -> (usize, Option<usize>);
+
+	/// Returns the last of the first `len` items, if available.
+	///
+	/// [`iterators::Iter`] uses this to iterate from the back.
+	///
+	/// The default implementation walks [`Iteratee::head_rest`] `len` times.
+	fn init_last(&self, len: usize) -> Option<&T> {
+		if len == 0 {
+			return None;
+		}
+
+		let (mut last, mut rest) = self.head_rest();
+		for _ in 1..len {
+			let (head, rest_) = rest.head_rest();
+			last = head;
+			rest = rest_;
+		}
+		last
+	}
 }
 
 /// A target for mutating dynamic dispatch iteration.
@@ -37,21 +71,128 @@ pub unsafe trait Iteratee<T: ?Sized> {
 ///
 /// * the target of the [`&dyn IterateeMut<T>`](`IterateeMut`) returned from [`IterateeMut::head_rest_mut`] must implement it too and
 /// * the target of the [`dyn Iteratee<T>`](`Iteratee`) [reference](https://doc.rust-lang.org/stable/core/primitive.reference.html) returned from [`IterateeMut::as_iteratee`] must implement it too.
+///
+/// [`IterateeMut::cursor_mut`] and [`IterateeMut::cursor_mut_of`] must either both return [`None`]
+/// or both return the [`CursorMut`] of `Self`, which must meet the requirements documented on its fields.
 pub unsafe trait IterateeMut<T: ?Sized>: Iteratee<T> {
 	/// Returns the first item reference, if available, and "rest of the sequence"-iteratee.
 	fn head_rest_mut(&mut self) -> (Option<&mut T>, &mut dyn IterateeMut<T>);
 
 	/// Borrows this instance as shared [`Iteratee<T>`];
 	fn as_iteratee(&self) -> &dyn Iteratee<T>;
+
+	/// Returns the [`CursorMut`] of `Self`, if any, see [`IterateeMut::cursor_mut_of`].
+	///
+	/// The default implementation returns [`None`].
+	fn cursor_mut(&self) -> Option<CursorMut<T>> {
+		None
+	}
+
+	/// Returns the [`CursorMut`] of `Self`, if any.
+	///
+	/// [`iterators::IterMut`] iterates through it where available, which lets it iterate from both ends.
+	/// A split through [`IterateeMut::head_rest_mut`] can't do that,
+	/// as it reborrows the front of the sequence, which contains the items already lent out from the back.
+	///
+	/// The default implementation returns [`None`].
+	#[must_use]
+	fn cursor_mut_of() -> Option<CursorMut<T>>
+	where
+		Self: Sized,
+	{
+		None
+	}
+}
+
+/// Pointer-based access to the items of an [`IterateeMut`], see [`CursorMut::cursor`].
+///
+/// Each function takes a pointer to an instance of the type whose cursor it is.
+/// Unlike [`Iteratee::head_rest`], they never create references to anything but the returned item,
+/// so that items from both ends of a sequence can be borrowed at once.
+#[allow(clippy::type_complexity)]
+pub struct Cursor<T: ?Sized> {
+	/// Returns the first item pointer, if available, and the "rest of the sequence" pointer with its cursor.
+	///
+	/// # Safety
+	///
+	/// Callers must pass a pointer to a live instance of the type whose cursor this is.
+	///
+	/// The item must be [`Some`] exactly if the sequence isn't empty.
+	/// The rest must then be exactly one item shorter and not contain the returned item.
+	pub head_rest: unsafe fn(*const ()) -> (Option<*const T>, *const (), Cursor<T>),
+
+	/// Returns the pointer to the last of the first `len` items, if available.
+	///
+	/// The first `len - 1` items remain as "init of the sequence".
+	///
+	/// # Safety
+	///
+	/// Callers must pass a pointer to a live instance of the type whose cursor this is,
+	/// and a `len` no greater than its exact [`size_hint`](`Iteratee::size_hint`).
+	///
+	/// The item must be [`Some`] exactly if `len` isn't zero, and must then be the `len`th item from the front.
+	/// It must be disjoint from the first `len - 1` items, so that it's never also reached through [`head_rest`](`Cursor::head_rest`)
+	/// before the front and back meet.
+	pub init_last: unsafe fn(*const (), usize) -> Option<*const T>,
+}
+
+impl<T: ?Sized> Clone for Cursor<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T: ?Sized> Copy for Cursor<T> {}
+
+/// Pointer-based mutable access to the items of an [`IterateeMut`], see [`IterateeMut::cursor_mut_of`].
+///
+/// See [`Cursor`].
+#[allow(clippy::type_complexity)]
+pub struct CursorMut<T: ?Sized> {
+	/// Returns the first item pointer, if available, and the "rest of the sequence" pointer with its cursor.
+	///
+	/// # Safety
+	///
+	/// See [`Cursor::head_rest`].
+	pub head_rest: unsafe fn(*mut ()) -> (Option<*mut T>, *mut (), CursorMut<T>),
+
+	/// Returns the pointer to the last of the first `len` items, if available.
+	///
+	/// The first `len - 1` items remain as "init of the sequence".
+	///
+	/// # Safety
+	///
+	/// See [`Cursor::init_last`].
+	/// Overlap with the front would let [`iterators::IterMut`] hand out aliasing `&mut`.
+	pub init_last: unsafe fn(*mut (), usize) -> Option<*mut T>,
+
+	/// The shared [`Cursor`] of the same type.
+	pub cursor: Cursor<T>,
 }
 
+impl<T: ?Sized> Clone for CursorMut<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T: ?Sized> Copy for CursorMut<T> {}
+
 mod private {
-	use core::ops::{Deref, DerefMut};
+	use core::{
+		convert::Infallible,
+		ops::{Deref, DerefMut},
+	};
 
 	use crate::{DynIteratee, Iteratee, IterateeMut};
 
 	pub trait Sealed {}
 
+	/// Type-level marker of an item type missing from a list.
+	///
+	/// See [`NotContains`](`crate::NotContains`).
+	pub struct Absent(Infallible);
+
 	macro_rules! impl_sealed {
 		($(
 			$ty:ty
@@ -72,7 +213,7 @@ mod private {
 	);
 
 	#[doc(hidden)]
-	pub trait DynIterateeImpl: Deref {
+	pub trait DynIterateeImpl: Deref + Copy {
 		type Item: ?Sized;
 
 		fn as_ref(&self) -> &dyn Iteratee<Self::Item>;
@@ -102,7 +243,7 @@ mod private {
 	#[doc(hidden)]
 	pub trait DynIterateeMutImpl: DerefMut {
 		type Item: ?Sized;
-		type DynIteratee: DynIteratee;
+		type DynIteratee: DynIteratee<Item = Self::Item>;
 
 		fn into_dyn_iteratee(self) -> Self::DynIteratee;
 
@@ -140,323 +281,2957 @@ mod private {
 		dyn IterateeMut<T> + Send + Sync,
 	);
 }
-use core::{convert::Infallible, mem::ManuallyDrop};
+use core::{convert::Infallible, marker::PhantomData, mem::ManuallyDrop, ptr::addr_of_mut};
 
 use iterators::{Iter, IterMut};
 use private::{DynIterateeImpl, DynIterateeMutImpl, Sealed};
 
+/// Shared [`Iteratee`] trait object references usable with [`Iter`].
+///
+/// This trait is sealed.
 pub trait DynIteratee: Sealed + DynIterateeImpl {}
 impl<T: ?Sized> DynIteratee for &dyn Iteratee<T> {}
 impl<T: ?Sized> DynIteratee for &(dyn Iteratee<T> + Send) {}
 impl<T: ?Sized> DynIteratee for &(dyn Iteratee<T> + Sync) {}
 impl<T: ?Sized> DynIteratee for &(dyn Iteratee<T> + Send + Sync) {}
+
+/// Exclusive [`IterateeMut`] trait object references usable with [`IterMut`].
+///
+/// This trait is sealed.
 pub trait DynIterateeMut: Sealed + DynIterateeMutImpl {}
 impl<T: ?Sized> DynIterateeMut for &mut dyn IterateeMut<T> {}
 impl<T: ?Sized> DynIterateeMut for &mut (dyn IterateeMut<T> + Send) {}
 impl<T: ?Sized> DynIterateeMut for &mut (dyn IterateeMut<T> + Sync) {}
 impl<T: ?Sized> DynIterateeMut for &mut (dyn IterateeMut<T> + Send + Sync) {}
 
-pub trait DynIter {
-	type DynIteratee: DynIteratee;
-	fn dyn_iter(&self) -> Iter<'_, Self::DynIteratee>;
+/// Shared dynamic dispatch iteration.
+///
+/// This is implemented for each [`Iteratee`] and each [`DynIteratee`] flavour that its marker traits allow,
+/// so the `+ Send` and `+ Sync` variants are picked up automatically where the expected [`Iter`] type requires them.
+pub trait DynIter<'a, I: DynIteratee>
+where
+	I::Item: 'a,
+{
+	/// Creates an [`Iter`] over `self`.
+	fn dyn_iter(&'a self) -> Iter<'a, I>;
 }
-pub trait DynIterMut {
-	type DynIterateeMut: DynIterateeMut;
-	fn dyn_iter_mut(&self) -> IterMut<'_, Self::DynIterateeMut>;
+
+/// Mutating dynamic dispatch iteration.
+///
+/// This is implemented for each [`IterateeMut`] and each [`DynIterateeMut`] flavour that its marker traits allow,
+/// so the `+ Send` and `+ Sync` variants are picked up automatically where the expected [`IterMut`] type requires them.
+pub trait DynIterMut<'a, I: DynIterateeMut>
+where
+	I::Item: 'a,
+{
+	/// Creates an [`IterMut`] over `self`.
+	fn dyn_iter_mut(&'a mut self) -> IterMut<'a, I>;
 }
 
-pub trait StaticIter<T: ?Sized> {
-	fn try_for_each<E>(self, on_each: impl FnMut(T) -> Result<(), E>) -> Result<(), E>
-	where
-		T: Sized;
-	fn try_for_each_ref<E>(&self, on_each: impl FnMut(&T) -> Result<(), E>) -> Result<(), E>;
-	fn try_for_each_mut<E>(
-		&mut self,
-		on_each: impl FnMut(&mut T) -> Result<(), E>,
-	) -> Result<(), E>;
+macro_rules! impl_dyn_iter {
+	($(
+		[$($marker:ident)*]
+	),*$(,)?) => {$(
+		impl<'a, T: ?Sized + 'a, S: Iteratee<T> $(+ $marker)* + 'a> DynIter<'a, &'a (dyn Iteratee<T> $(+ $marker)* + 'a)> for S {
+			fn dyn_iter(&'a self) -> Iter<'a, &'a (dyn Iteratee<T> $(+ $marker)* + 'a)> {
+				Iter::new(self)
+			}
+		}
 
-	fn for_each(self, mut on_each: impl FnMut(T))
-	where
-		Self: Sized,
-		T: Sized,
-	{
-		self.try_for_each(move |item| Ok::<_, Infallible>(on_each(item)))
-			.unwrap()
-	}
-	fn for_each_ref(&self, mut on_each: impl FnMut(&T)) {
-		self.try_for_each_ref(move |item| Ok::<_, Infallible>(on_each(item)))
-			.unwrap()
+		impl<'a, T: ?Sized + 'a, S: IterateeMut<T> $(+ $marker)* + 'a> DynIterMut<'a, &'a mut (dyn IterateeMut<T> $(+ $marker)* + 'a)> for S {
+			fn dyn_iter_mut(&'a mut self) -> IterMut<'a, &'a mut (dyn IterateeMut<T> $(+ $marker)* + 'a)> {
+				IterMut::new(self)
+			}
+		}
+	)*};
+}
+
+impl_dyn_iter!([], [Send], [Sync], [Send Sync]);
+
+/// Static list length.
+pub trait Len {
+	/// The number of items in this list.
+	const LEN: usize;
+
+	/// Returns [`Self::LEN`].
+	fn len(&self) -> usize {
+		Self::LEN
 	}
-	fn for_each_mut(&mut self, mut on_each: impl FnMut(&mut T)) {
-		self.try_for_each_mut(move |item| Ok::<_, Infallible>(on_each(item)))
-			.unwrap()
+
+	/// Returns whether [`Self::LEN`] is zero.
+	fn is_empty(&self) -> bool {
+		Self::LEN == 0
 	}
+}
 
-	fn try_fold<A, E, F: FnMut(A, T) -> Result<A, E>>(
-		self,
-		initial: A,
-		mut on_fold: F,
-	) -> Result<A, E>
-	where
-		Self: Sized,
-		T: Sized,
-	{
-		let mut aggregate = ManuallyDrop::new(initial);
-		let aggregate_ = &mut *aggregate as *mut A;
-		self.try_for_each(move |item| unsafe {
-			Ok(aggregate_.write(on_fold(aggregate_.read(), item)?))
-		})?;
-		Ok(ManuallyDrop::into_inner(aggregate))
+/// Zero-runtime-cost iteration over shared item references.
+///
+/// Each item is viewed as `T` through [`Borrow`](`core::borrow::Borrow`),
+/// so a list of distinct types can be walked as for example `dyn Trait`,
+/// as long as each item type implements this conversion.
+///
+/// See [`StaticIterMut`] for exclusive item references and [`StaticIntoIter`] for by-value iteration.
+pub trait StaticIter<T: ?Sized> {
+	/// Calls `on_each` with a reference to each item in order,
+	/// stopping at the first error.
+	///
+	/// # Errors
+	///
+	/// Iff `on_each` returns an error.
+	fn try_for_each_ref<E>(&self, on_each: impl FnMut(&T) -> Result<(), E>) -> Result<(), E>;
+
+	/// Calls `on_each` with a reference to each item in order.
+	fn for_each_ref(&self, mut on_each: impl FnMut(&T)) {
+		self.try_for_each_ref(move |item| {
+			on_each(item);
+			Ok::<_, Infallible>(())
+		})
+		.unwrap()
 	}
 
+	/// Folds item references into an aggregate, stopping at the first error.
+	///
+	/// # Errors
+	///
+	/// Iff `on_fold` returns an error.
 	fn try_fold_ref<A, E, F: FnMut(A, &T) -> Result<A, E>>(
 		&self,
 		initial: A,
 		mut on_fold: F,
-	) -> Result<A, E>
-	where
-		Self: Sized,
-		T: Sized,
-	{
+	) -> Result<A, E> {
 		let mut aggregate = ManuallyDrop::new(initial);
-		let aggregate_ = &mut *aggregate as *mut A;
+		let aggregate_ = addr_of_mut!(*aggregate);
 		self.try_for_each_ref(move |item| unsafe {
-			Ok(aggregate_.write(on_fold(aggregate_.read(), item)?))
+			aggregate_.write(on_fold(aggregate_.read(), item)?);
+			Ok(())
 		})?;
 		Ok(ManuallyDrop::into_inner(aggregate))
 	}
 
+	/// Folds item references into an aggregate.
+	fn fold_ref<A, F: FnMut(A, &T) -> A>(&self, initial: A, mut on_fold: F) -> A {
+		self.try_fold_ref(initial, move |aggregate, item| {
+			Ok::<_, Infallible>(on_fold(aggregate, item))
+		})
+		.unwrap()
+	}
+}
+
+/// Zero-runtime-cost iteration over exclusive item references.
+///
+/// Each item is viewed as `T` through [`BorrowMut`](`core::borrow::BorrowMut`).
+/// This is a separate trait from [`StaticIter`] so that items which only implement [`Borrow`](`core::borrow::Borrow`),
+/// like [`Rc`](https://doc.rust-lang.org/stable/alloc/rc/struct.Rc.html)s, can still be walked by reference.
+pub trait StaticIterMut<T: ?Sized>: StaticIter<T> {
+	/// Calls `on_each` with a mutable reference to each item in order,
+	/// stopping at the first error.
+	///
+	/// # Errors
+	///
+	/// Iff `on_each` returns an error.
+	fn try_for_each_mut<E>(
+		&mut self,
+		on_each: impl FnMut(&mut T) -> Result<(), E>,
+	) -> Result<(), E>;
+
+	/// Calls `on_each` with a mutable reference to each item in order.
+	fn for_each_mut(&mut self, mut on_each: impl FnMut(&mut T)) {
+		self.try_for_each_mut(move |item| {
+			on_each(item);
+			Ok::<_, Infallible>(())
+		})
+		.unwrap()
+	}
+
+	/// Folds mutable item references into an aggregate, stopping at the first error.
+	///
+	/// # Errors
+	///
+	/// Iff `on_fold` returns an error.
 	fn try_fold_mut<A, E, F: FnMut(A, &mut T) -> Result<A, E>>(
 		&mut self,
 		initial: A,
 		mut on_fold: F,
-	) -> Result<A, E>
-	where
-		Self: Sized,
-		T: Sized,
-	{
+	) -> Result<A, E> {
 		let mut aggregate = ManuallyDrop::new(initial);
-		let aggregate_ = &mut *aggregate as *mut A;
+		let aggregate_ = addr_of_mut!(*aggregate);
 		self.try_for_each_mut(move |item| unsafe {
-			Ok(aggregate_.write(on_fold(aggregate_.read(), item)?))
+			aggregate_.write(on_fold(aggregate_.read(), item)?);
+			Ok(())
 		})?;
 		Ok(ManuallyDrop::into_inner(aggregate))
 	}
 
-	fn fold<A, E, F: FnMut(A, T) -> A>(self, initial: A, mut on_fold: F) -> A
-	where
-		Self: Sized,
-		T: Sized,
-	{
-		self.try_fold(initial, move |aggregate, item| {
+	/// Folds mutable item references into an aggregate.
+	fn fold_mut<A, F: FnMut(A, &mut T) -> A>(&mut self, initial: A, mut on_fold: F) -> A {
+		self.try_fold_mut(initial, move |aggregate, item| {
 			Ok::<_, Infallible>(on_fold(aggregate, item))
 		})
 		.unwrap()
 	}
+}
+
+/// Zero-runtime-cost by-value iteration.
+///
+/// Each item is converted into `T` through [`Into`].
+pub trait StaticIntoIter<T> {
+	/// Calls `on_each` with each item in order,
+	/// stopping at the first error.
+	///
+	/// Remaining items are dropped in that case.
+	///
+	/// # Errors
+	///
+	/// Iff `on_each` returns an error.
+	fn try_for_each<E>(self, on_each: impl FnMut(T) -> Result<(), E>) -> Result<(), E>;
 
-	fn fold_ref<A, E, F: FnMut(A, &T) -> A>(&self, initial: A, mut on_fold: F) -> A
+	/// Calls `on_each` with each item in order.
+	fn for_each(self, mut on_each: impl FnMut(T))
 	where
 		Self: Sized,
-		T: Sized,
 	{
-		self.try_fold_ref(initial, move |aggregate, item| {
-			Ok::<_, Infallible>(on_fold(aggregate, item))
+		self.try_for_each(move |item| {
+			on_each(item);
+			Ok::<_, Infallible>(())
 		})
 		.unwrap()
 	}
 
-	fn fold_mut<A, E, F: FnMut(A, &mut T) -> A>(&mut self, initial: A, mut on_fold: F) -> A
+	/// Folds items into an aggregate, stopping at the first error.
+	///
+	/// # Errors
+	///
+	/// Iff `on_fold` returns an error.
+	fn try_fold<A, E, F: FnMut(A, T) -> Result<A, E>>(
+		self,
+		initial: A,
+		mut on_fold: F,
+	) -> Result<A, E>
+	where
+		Self: Sized,
+	{
+		let mut aggregate = ManuallyDrop::new(initial);
+		let aggregate_ = addr_of_mut!(*aggregate);
+		self.try_for_each(move |item| unsafe {
+			aggregate_.write(on_fold(aggregate_.read(), item)?);
+			Ok(())
+		})?;
+		Ok(ManuallyDrop::into_inner(aggregate))
+	}
+
+	/// Folds items into an aggregate.
+	fn fold<A, F: FnMut(A, T) -> A>(self, initial: A, mut on_fold: F) -> A
 	where
 		Self: Sized,
-		T: Sized,
 	{
-		self.try_fold_mut(initial, move |aggregate, item| {
+		self.try_fold(initial, move |aggregate, item| {
 			Ok::<_, Infallible>(on_fold(aggregate, item))
 		})
 		.unwrap()
 	}
 }
 
-#[doc(hidden)]
-pub mod __ {
-	#[doc(hidden)]
-	#[macro_export]
-	macro_rules! custom_list_types {
-		(
-			$(#[$listMeta:meta])*
-			$listVis:vis struct $List:ident
-				$([$($generics:tt)*][$($generics0:tt)*])?
-				$(where [$($constraints:tt)*][$($constraints2:tt)*])?
-			{
-				$listFieldVis:vis head: $itemTy:ty,
-				..
-			},
+/// A polymorphic folding step, with output types depending on accumulator and item types.
+///
+/// Implement this once per accumulator and item type combination to fold lists of distinct types,
+/// through [`FoldLeft`] and [`FoldRight`].
+/// Closures implement it for their (single) pair of argument types.
+pub trait Folder<Acc, Item> {
+	/// The next accumulator type.
+	type Output;
 
-			$(#[$endMeta:meta])*
-			$endVis:vis struct $End:ident,
+	/// Combines `acc` with `item`.
+	fn fold(&mut self, acc: Acc, item: Item) -> Self::Output;
+}
 
-			$(#[$consMeta:meta])*
-			$consVis:vis trait $Cons:ident$(,
-				$wrapperVis:vis struct $Wrapper:ident
-			)?$(,)?
-		) => {
-			$(#[$listMeta])*
-			$listVis struct $List<$($($generics)*,)? R>
-				$(where $($constraints)*,)?
-			{
-				/// The first item of the list.
-				$listFieldVis head: $itemTy,
+impl<F: FnMut(Acc, Item) -> Output, Acc, Item, Output> Folder<Acc, Item> for F {
+	type Output = Output;
 
-				/// The rest of the list.
-				$listFieldVis rest: R,
-			}
+	fn fold(&mut self, acc: Acc, item: Item) -> Output {
+		self(acc, item)
+	}
+}
 
-			$(#[$endMeta])*
-			$endVis struct End;
-
-			$(#[$consMeta])*
-			$consVis trait Cons$(<$($generics)*>)?
-				$(where
-					$($constraints)*,
-					$($constraints2)*,
-				)?
-			{
-				/// The result of a `cons` operation, with `T` prepended as head.
-				type Cons;
-				/// The results of an `r_cons` operation, with `T` appended as tail.
-				type RCons;
+/// Heterogeneous folding from first to last item.
+///
+/// Unlike [`StaticIntoIter::fold`], the accumulator may change its type with each step.
+pub trait FoldLeft<F, Acc> {
+	/// The final accumulator type.
+	type Output;
 
-				/// Transforms `self` by prepending `head`.
-				fn cons(self, head: $itemTy) -> Self::Cons;
+	/// Folds each item in order into `acc` using `folder`.
+	fn foldl(self, folder: F, acc: Acc) -> Self::Output;
+}
 
-				/// Transforms `self` by appending `tail`.
-				fn r_cons(self, tail: $itemTy) -> Self::RCons;
-			}
-		};
+/// Heterogeneous folding from last to first item.
+///
+/// This is implemented for each list that can be [`Reverse`]d into a [`FoldLeft`] implementor.
+pub trait FoldRight<F, Acc> {
+	/// The final accumulator type.
+	type Output;
+
+	/// Folds each item in reverse order into `acc` using `folder`.
+	fn foldr(self, folder: F, acc: Acc) -> Self::Output;
+}
+
+impl<L: Reverse, F, Acc> FoldRight<F, Acc> for L
+where
+	L::Output: FoldLeft<F, Acc>,
+{
+	type Output = <L::Output as FoldLeft<F, Acc>>::Output;
+
+	fn foldr(self, folder: F, acc: Acc) -> Self::Output {
+		self.reverse().foldl(folder, acc)
 	}
-	pub use custom_list_types;
 }
 
-/// Creates and implements a custom cons list.
-#[macro_export]
-macro_rules! custom_list {
-	(
-		$(#[$listMeta:meta])*
-		$listVis:vis struct $List:ident
-			$([$($generics:tt)*][$($generics0:tt)*])?
-			$(where [$($constraints:tt)*][$($constraints2:tt)*])?
-		{
-			$listFieldVis:vis head: $itemTy:ty,
-			..
-		},
+/// Type-level index of a list's head.
+///
+/// See [`Selector`].
+pub struct Here(Infallible);
 
-		$(#[$endMeta:meta])*
-		$endVis:vis struct $End:ident,
+/// Type-level index of an item in a list's rest, at index `I` there.
+///
+/// See [`Selector`].
+pub struct There<I>(Infallible, PhantomData<I>);
 
-		$(#[$consMeta:meta])*
-		$consVis:vis trait $Cons:ident
-		$(,)?
-	) => {
-		$crate::__::custom_list_types!(
-			$(#[$listMeta])* $listVis struct $List$([$($generics)*][$($generics0)*])? {
-				$listFieldVis head: $itemTy,
-				..
-			},
-			$(#[$endMeta])* $endVis struct $End,
-			$(#[$consMeta])* $consVis trait $Cons,
-		);
+/// Type-indexed item access.
+///
+/// `I` is a type-level index ([`Here`] or [`There`]) that's normally inferred.
+/// If a list contains `T` more than once, inference fails and the index must be spelled out.
+pub trait Selector<T, I> {
+	/// The list that remains after [`Selector::pluck`]ing `T`.
+	type Remainder;
 
-		impl$(<$($generics)*>)? $Cons$(<$($generics)*>)? for $End {
-			type Cons = $List<$($($generics)*,)? Self>;
-			type RCons = $List<$($($generics)*,)? Self>;
-			fn cons(self, head: $itemTy) -> <Self as $Cons$(<$($generics)*>)?>::Cons {
-				$List {
-					head,
-					rest: self,
-				}
-			}
-			fn r_cons(self, tail: $itemTy) -> <Self as $Cons$(<$($generics)*>)?>::RCons {
-				$List {
-					head: tail,
-					rest: self,
-				}
-			}
-  		}
+	/// Borrows the item of type `T`.
+	fn get(&self) -> &T;
 
-		impl<
-			$(
-				$($generics)*,
-				$($generics0)*,
-			)?
-			R: $Cons$(<$($generics)*>)?,
-		> $Cons$(<$($generics)*>)? for $List<$($($generics0)*,)? R> {
-			type Cons = $List<$($($generics)*,)? Self>;
-			type RCons = $List<$($($generics0)*,)? <R as $Cons$(<$($generics)*>)?>::RCons>;
-			fn cons(self, head: $itemTy) -> <Self as $Cons$(<$($generics)*>)?>::Cons {
-				$List {
-					head,
-					rest: self,
-				}
-			}
-			fn r_cons(self, tail: $itemTy) -> <Self as $Cons$(<$($generics)*>)?>::RCons {
-				$List {
-					head: self.head,
-					rest: <R as $Cons$(<$($generics)*>)?>::r_cons(self.rest, tail),
-				}
-			}
-		}
+	/// Mutably borrows the item of type `T`.
+	fn get_mut(&mut self) -> &mut T;
 
-		impl<X: ?Sized> $crate::StaticIter<X> for $End {
+	/// Moves the item of type `T` out of the list, returning it with the remaining list.
+	fn pluck(self) -> (T, Self::Remainder);
+}
+
+/// Reordering a list into a permutation or subset of its items.
+///
+/// `Target` is a [`List`](struct@List) (or [`End`]) of item types each found in `Self` through [`Selector`].
+/// `Indices` is a matching [`List`](struct@List) of their type-level indices, which is normally inferred.
+pub trait Sculptor<Target, Indices> {
+	/// The list of items not moved into `Target`.
+	type Remainder;
+
+	/// Moves the items of `Target` out of this list, in `Target`'s order,
+	/// returning them with the remaining list.
+	fn sculpt(self) -> (Target, Self::Remainder);
+}
+
+impl<Source> Sculptor<End, End> for Source {
+	type Remainder = Source;
+
+	fn sculpt(self) -> (End, Source) {
+		(End, self)
+	}
+}
+
+impl<Source, THead, TRest, IHead, IRest> Sculptor<List<THead, TRest>, List<IHead, IRest>> for Source
+where
+	Source: Selector<THead, IHead>,
+	Source::Remainder: Sculptor<TRest, IRest>,
+{
+	type Remainder = <Source::Remainder as Sculptor<TRest, IRest>>::Remainder;
+
+	fn sculpt(self) -> (List<THead, TRest>, Self::Remainder) {
+		let (head, remainder) = self.pluck();
+		let (rest, remainder) = remainder.sculpt();
+		(List { head, rest }, remainder)
+	}
+}
+
+/// Type membership.
+///
+/// This is implemented for all [`Selector`] implementors.
+/// `I` is a type-level index ([`Here`] or [`There`]) that's normally inferred.
+pub trait Contains<T, I> {}
+
+impl<L: Selector<T, I>, T, I> Contains<T, I> for L {}
+
+/// Type-level marker of an item type present in a list at index `I`.
+///
+/// See [`NotContains`].
+pub struct Present<I>(Infallible, PhantomData<I>);
+
+/// Type non-membership.
+///
+/// `I` must be left to inference, which succeeds only if `Self` doesn't [contain](`Contains`) `T`.
+/// Otherwise, the bound fails with a "type annotations needed" error naming this trait.
+/// The index inferred in the success case is private, so that it can't be named to skip this check.
+///
+/// Stable Rust can't express type inequality, so this is as close as a trait bound can get.
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{List, NotContains};
+///
+/// fn without_u8<L: NotContains<u8, I>, I>() {}
+///
+/// without_u8::<List![u16, char], _>();
+/// ```
+///
+/// ```rust,compile_fail,E0283
+/// # use geode::{List, NotContains};
+/// # fn without_u8<L: NotContains<u8, I>, I>() {}
+/// without_u8::<List![u16, u8], _>();
+/// ```
+pub trait NotContains<T, I> {}
+
+impl<L, T> NotContains<T, private::Absent> for L {}
+impl<L: Contains<T, I>, T, I> NotContains<T, Present<I>> for L {}
+
+/// Item type uniqueness.
+///
+/// `Indices` must be left to inference, which succeeds only if no item type occurs twice in `Self`.
+/// Otherwise, like with [`NotContains`], the bound fails with a "type annotations needed" error.
+/// See [`assert_unique_types!`] for a static assertion built on this.
+pub trait UniqueTypes<Indices> {}
+
+/// Item type subset relation.
+///
+/// `Indices` is a [`List`](struct@List) of the items' type-level indices in `Other`, which is normally inferred.
+pub trait IsSubsetOf<Other, Indices> {
+	/// `Other` without the item types of `Self`.
+	type Complement;
+}
+
+/// Item type disjointness.
+///
+/// `Indices` must be left to inference, which succeeds only if no item type of `Self` is also one of `Other`.
+/// See [`NotContains`].
+pub trait IsDisjointFrom<Other, Indices> {}
+
+/// Item type set union into a named target.
+///
+/// `Target` is a [`List`](struct@List) (or [`End`]) of exactly the item types found in `Self` or `Other`, in any order.
+/// Stable Rust can't compute this type, so it must be named, usually through a type annotation like with [`Into`], and is then checked.
+/// `Indices` is normally inferred.
+///
+/// All lists involved must have unique item types.
+pub trait UnionInto<Other, Target, Indices> {
+	/// Moves the items of `Target` out of `self` or, for those not found there, `other`.
+	fn union_into(self, other: Other) -> Target;
+}
+
+// `Own` is `Self` moved into a [`List`](struct@List) in `Target`'s order,
+// as custom lists only concatenate with their own kind.
+type Own<L, Target, I1, I5> =
+	<Target as Sculptor<<L as IsSubsetOf<Target, I1>>::Complement, I5>>::Remainder;
+
+impl<L, Other, Target, I0, I1, I2, I3, I4, I5, I6>
+	UnionInto<Other, Target, (I0, I1, I2, I3, I4, I5, I6)> for L
+where
+	Target: UniqueTypes<I0> + Sculptor<<L as IsSubsetOf<Target, I1>>::Complement, I5>,
+	L: IsSubsetOf<Target, I1> + Sculptor<Own<L, Target, I1, I5>, I6>,
+	Other: Sculptor<<L as IsSubsetOf<Target, I1>>::Complement, I2>,
+	Other::Remainder: IsSubsetOf<L, I3>,
+	Own<L, Target, I1, I5>: Concat<<L as IsSubsetOf<Target, I1>>::Complement>,
+	<Own<L, Target, I1, I5> as Concat<<L as IsSubsetOf<Target, I1>>::Complement>>::Output:
+		Sculptor<Target, I4>,
+{
+	fn union_into(self, other: Other) -> Target {
+		let (own, _) = self.sculpt();
+		let (complement, _) = other.sculpt();
+		own.concat(complement).sculpt().0
+	}
+}
+
+/// Item type set intersection into a named target.
+///
+/// `Target` is a [`List`](struct@List) (or [`End`]) of exactly the item types found in both `Self` and `Other`, in any order.
+/// Stable Rust can't compute this type, so it must be named, usually through a type annotation like with [`Into`], and is then checked.
+/// `Indices` is normally inferred.
+///
+/// All lists involved must have unique item types.
+pub trait IntersectionInto<Other, Target, Indices> {
+	/// Moves the items of `Target` out of `self`, dropping the rest.
+	///
+	/// `other` only determines which items those are.
+	fn intersection_into(self, other: &Other) -> Target;
+}
+
+impl<L, Other, Target, I0, I1, I2> IntersectionInto<Other, Target, (I0, I1, I2)> for L
+where
+	L: Sculptor<Target, I0>,
+	Target: IsSubsetOf<Other, I1>,
+	L::Remainder: IsDisjointFrom<Other, I2>,
+{
+	fn intersection_into(self, _: &Other) -> Target {
+		self.sculpt().0
+	}
+}
+
+/// Item type set difference into a named target.
+///
+/// `Target` is a [`List`](struct@List) (or [`End`]) of exactly the item types found in `Self` but not `Other`, in any order.
+/// Stable Rust can't compute this type, so it must be named, usually through a type annotation like with [`Into`], and is then checked.
+/// `Indices` is normally inferred.
+///
+/// All lists involved must have unique item types.
+/// If `Other` is a subset of `Self`, [`Sculptor::Remainder`] computes the difference instead.
+pub trait DifferenceInto<Other, Target, Indices> {
+	/// Moves the items of `Target` out of `self`, dropping the rest.
+	///
+	/// `other` only determines which items those are.
+	fn difference_into(self, other: &Other) -> Target;
+}
+
+impl<L, Other, Target, I0, I1, I2> DifferenceInto<Other, Target, (I0, I1, I2)> for L
+where
+	L: Sculptor<Target, I0>,
+	L::Remainder: IsSubsetOf<Other, I1>,
+	Target: IsDisjointFrom<Other, I2>,
+{
+	fn difference_into(self, _: &Other) -> Target {
+		self.sculpt().0
+	}
+}
+
+/// Positional item access by type-level index.
+///
+/// `I` is [`Here`] or nested [`There`]s. See [`At`] for the same with a `const` index.
+pub trait AtIndex<I> {
+	/// The type of the item at `I`.
+	type Output;
+
+	/// The list that remains after [`AtIndex::take_at_index`].
+	type Remainder;
+
+	/// Borrows the item at `I`.
+	fn at_index(&self) -> &Self::Output;
+
+	/// Mutably borrows the item at `I`.
+	fn at_index_mut(&mut self) -> &mut Self::Output;
+
+	/// Moves the item at `I` out of the list, returning it with the remaining list.
+	fn take_at_index(self) -> (Self::Output, Self::Remainder);
+}
+
+/// A `const` index as type.
+///
+/// [`TypeIndex`] is implemented for `N` up to and including 63.
+pub struct Const<const N: usize>(Infallible);
+
+/// Converts a [`Const`] into the equivalent type-level index.
+pub trait TypeIndex {
+	/// [`Here`] or nested [`There`]s.
+	type Index;
+}
+
+macro_rules! impl_type_index {
+	($index:ty; $n:literal $(, $rest:literal)*$(,)?) => {
+		impl TypeIndex for Const<$n> {
+			type Index = $index;
+		}
+
+		impl_type_index!(There<$index>; $($rest),*);
+	};
+	($index:ty;) => {};
+}
+
+impl_type_index!(Here;
+	0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+	16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+	32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+	48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+);
+
+/// Positional item access by `const` index.
+///
+/// This is implemented for all [`AtIndex`] implementors, for `N` up to and including 63.
+/// `<L as At<N>>::Output` names the type of the item at `N` in `L`.
+///
+/// Out-of-range indices fail to compile.
+pub trait At<const N: usize> {
+	/// The type of the item at `N`.
+	type Output;
+
+	/// The list that remains after [`At::take_at`].
+	type Remainder;
+
+	/// Borrows the item at `N`.
+	fn at(&self) -> &Self::Output;
+
+	/// Mutably borrows the item at `N`.
+	fn at_mut(&mut self) -> &mut Self::Output;
+
+	/// Moves the item at `N` out of the list, returning it with the remaining list.
+	fn take_at(self) -> (Self::Output, Self::Remainder);
+}
+
+impl<L: AtIndex<<Const<N> as TypeIndex>::Index>, const N: usize> At<N> for L
+where
+	Const<N>: TypeIndex,
+{
+	type Output = L::Output;
+	type Remainder = L::Remainder;
+
+	fn at(&self) -> &Self::Output {
+		self.at_index()
+	}
+
+	fn at_mut(&mut self) -> &mut Self::Output {
+		self.at_index_mut()
+	}
+
+	fn take_at(self) -> (Self::Output, Self::Remainder) {
+		self.take_at_index()
+	}
+}
+
+/// List concatenation.
+///
+/// List types generated by [`custom_list!`] also implement [`Add`](`core::ops::Add`) through this trait.
+/// They only concatenate with lists from the same macro invocation:
+///
+/// ```compile_fail
+/// geode::custom_list!(
+///     pub struct Other {
+///         pub head: u8,
+///         ..
+///     },
+///     pub struct OtherEnd,
+///     pub trait OtherCons,
+/// );
+///
+/// let _ = geode::End + OtherEnd;
+/// ```
+pub trait Concat<Rhs> {
+	/// The concatenated list type, with the items of `Rhs` after those of `Self`.
+	type Output;
+
+	/// Appends the items of `rhs` to `self`.
+	fn concat(self, rhs: Rhs) -> Self::Output;
+}
+
+/// List reversal.
+pub trait Reverse {
+	/// The reversed list type.
+	type Output;
+
+	/// Reverses the order of items in this list.
+	fn reverse(self) -> Self::Output;
+}
+
+/// List reversal onto an accumulator list.
+///
+/// This is the building block of [`Reverse`].
+pub trait ReverseOnto<Acc> {
+	/// `Acc` with the items of `Self` prepended in reverse order.
+	type Output;
+
+	/// Prepends the items of `self` to `acc` in reverse order.
+	fn reverse_onto(self, acc: Acc) -> Self::Output;
+}
+
+/// Splitting a list at a type-level index.
+///
+/// `I` is [`Here`] or nested [`There`]s. See [`SplitAt`] for the same with a `const` index.
+pub trait SplitAtIndex<I> {
+	/// The list of items before `I`.
+	type Prefix;
+
+	/// The list of items starting at `I`.
+	type Suffix;
+
+	/// Splits this list before the item at `I`.
+	fn split_at_index(self) -> (Self::Prefix, Self::Suffix);
+}
+
+/// Splitting a list at a `const` index.
+///
+/// This is implemented for all [`SplitAtIndex`] implementors, for `N` up to and including 63.
+/// `N` may be equal to the list's length, in which case the suffix is empty.
+pub trait SplitAt<const N: usize> {
+	/// The list of the first `N` items.
+	type Prefix;
+
+	/// The list of the remaining items.
+	type Suffix;
+
+	/// Splits this list after its first `N` items.
+	fn split_at(self) -> (Self::Prefix, Self::Suffix);
+}
+
+impl<L: SplitAtIndex<<Const<N> as TypeIndex>::Index>, const N: usize> SplitAt<N> for L
+where
+	Const<N>: TypeIndex,
+{
+	type Prefix = L::Prefix;
+	type Suffix = L::Suffix;
+
+	fn split_at(self) -> (Self::Prefix, Self::Suffix) {
+		self.split_at_index()
+	}
+}
+
+/// Access to the first item of a non-empty list.
+pub trait First {
+	/// The type of the first item.
+	type Output;
+
+	/// Borrows the first item.
+	fn first(&self) -> &Self::Output;
+
+	/// Mutably borrows the first item.
+	fn first_mut(&mut self) -> &mut Self::Output;
+}
+
+/// Access to the last item of a non-empty list.
+pub trait Last {
+	/// The type of the last item.
+	type Output;
+
+	/// Borrows the last item.
+	fn last(&self) -> &Self::Output;
+
+	/// Mutably borrows the last item.
+	fn last_mut(&mut self) -> &mut Self::Output;
+}
+
+/// Removal of the last item of a non-empty list.
+pub trait Init {
+	/// The list of all but the last item.
+	type Output;
+
+	/// The single-item list containing only the last item.
+	type Last;
+
+	/// Splits this list before its last item.
+	fn split_last(self) -> (Self::Output, Self::Last);
+
+	/// Drops the last item of this list.
+	fn init(self) -> Self::Output
+	where
+		Self: Sized,
+	{
+		self.split_last().0
+	}
+}
+
+/// Moving the first item of a list to its end.
+pub trait RotateLeft {
+	/// The rotated list type.
+	type Output;
+
+	/// Moves the first item of this list to its end.
+	///
+	/// Empty lists are returned unchanged.
+	fn rotate_left(self) -> Self::Output;
+}
+
+/// Moving the last item of a list to its start.
+pub trait RotateRight {
+	/// The rotated list type.
+	type Output;
+
+	/// Moves the last item of this list to its start.
+	///
+	/// Empty lists are returned unchanged.
+	fn rotate_right(self) -> Self::Output;
+}
+
+/// Conversion of a list into a tuple of its items.
+///
+/// Lists generated by [`custom_list!`] implement this for up to 16 items.
+/// They can also be created from such tuples through [`From`].
+pub trait IntoTuple {
+	/// The tuple type, with one field per item.
+	type Tuple;
+
+	/// Moves the items of this list into a tuple.
+	fn into_tuple(self) -> Self::Tuple;
+}
+
+/// Borrowing a list as tuple of item references.
+///
+/// Lists generated by [`custom_list!`] implement this for up to 16 items.
+pub trait AsTupleRefs<'a> {
+	/// The tuple type, with one reference field per item.
+	type Refs;
+
+	/// Borrows each item of this list into a tuple.
+	fn as_tuple_refs(&'a self) -> Self::Refs;
+}
+
+/// A polymorphic function object, with output types depending on input types.
+///
+/// Implement this once per input type to [`Map`] lists of distinct types.
+/// Closures implement it for their (single) argument type.
+pub trait Func<Input> {
+	/// The result type for `Input`.
+	type Output;
+
+	/// Calls this function with `input`.
+	fn call(&mut self, input: Input) -> Self::Output;
+}
+
+impl<F: FnMut(Input) -> Output, Input, Output> Func<Input> for F {
+	type Output = Output;
+
+	fn call(&mut self, input: Input) -> Output {
+		self(input)
+	}
+}
+
+/// Item-wise list transformation through a [`Func`].
+///
+/// Lists generated by [`custom_list!`] map into [`List`](struct@List)s,
+/// as their own item types may not be able to hold the results.
+pub trait Map<F> {
+	/// The list of results.
+	type Output;
+
+	/// Calls `f` with each item in order, collecting the results into a new list.
+	fn map(self, f: F) -> Self::Output;
+}
+
+/// Item-wise list transformation of references through a [`Func`].
+///
+/// See [`Map`].
+pub trait MapRef<'a, F> {
+	/// The list of results.
+	type Output;
+
+	/// Calls `f` with a reference to each item in order, collecting the results into a new list.
+	fn map_ref(&'a self, f: F) -> Self::Output;
+}
+
+/// Item-wise list transformation of mutable references through a [`Func`].
+///
+/// See [`Map`].
+pub trait MapMut<'a, F> {
+	/// The list of results.
+	type Output;
+
+	/// Calls `f` with a mutable reference to each item in order, collecting the results into a new list.
+	fn map_mut(&'a mut self, f: F) -> Self::Output;
+}
+
+/// Conversion of a struct to and from a list of its fields.
+///
+/// This can be derived with the `derive` feature.
+pub trait Generic: Sized {
+	/// The [`List`](struct@List) of field types, in declaration order.
+	type Repr;
+
+	/// Moves the fields into a list.
+	fn into_repr(self) -> Self::Repr;
+
+	/// Assembles an instance from a list of its fields.
+	fn from_repr(repr: Self::Repr) -> Self;
+}
+
+/// Conversion of a struct to and from a list of its [`Field`]s, labelled with their names.
+///
+/// This can be derived with the `derive` feature. Tuple struct fields are labelled with their index.
+pub trait LabelledGeneric: Sized {
+	/// The [`List`](struct@List) of labelled fields, in declaration order.
+	type Repr;
+
+	/// Moves the fields into a list of [`Field`]s.
+	fn into_labelled_repr(self) -> Self::Repr;
+
+	/// Assembles an instance from a list of its [`Field`]s.
+	fn from_labelled_repr(repr: Self::Repr) -> Self;
+}
+
+/// A value with a type-level name, as used by [`LabelledGeneric`].
+///
+/// `Name` is a [`List`](struct@List) of [`labels`] spelling [`Field::name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Field<Name, T> {
+	/// The field's name.
+	pub name: &'static str,
+
+	/// The field's value.
+	pub value: T,
+
+	_name: PhantomData<Name>,
+}
+
+impl<Name, T> Field<Name, T> {
+	/// Labels `value` with `name`, which should be spelled by `Name`.
+	#[must_use]
+	pub const fn new(name: &'static str, value: T) -> Self {
+		Self {
+			name,
+			value,
+			_name: PhantomData,
+		}
+	}
+}
+
+/// Pairing the items of two lists of equal length.
+///
+/// Lists generated by [`custom_list!`] zip with lists of the same kind into [`List`](struct@List)s of pairs.
+/// Mismatched lengths fail to compile.
+pub trait Zip<Other> {
+	/// The list of item pairs.
+	type Output;
+
+	/// Pairs each item of `self` with the item at the same position in `other`.
+	fn zip(self, other: Other) -> Self::Output;
+}
+
+/// Combining the items of two lists of equal length through a [`Func`].
+///
+/// This is implemented for each [`Zip`] implementor whose output can be [`Map`]ped with `F`,
+/// which is called with item pairs.
+pub trait ZipWith<Other, F> {
+	/// The list of results.
+	type Output;
+
+	/// Calls `f` with each item of `self` paired with the item at the same position in `other`,
+	/// collecting the results into a new list.
+	fn zip_with(self, other: Other, f: F) -> Self::Output;
+}
+
+impl<L: Zip<Other>, Other, F> ZipWith<Other, F> for L
+where
+	L::Output: Map<F>,
+{
+	type Output = <L::Output as Map<F>>::Output;
+
+	fn zip_with(self, other: Other, f: F) -> Self::Output {
+		self.zip(other).map(f)
+	}
+}
+
+/// Splitting a [`List`](struct@List) of pairs into two lists.
+///
+/// This is the inverse of [`Zip`].
+pub trait Unzip {
+	/// The list of first pair items.
+	type Left;
+
+	/// The list of second pair items.
+	type Right;
+
+	/// Splits each pair in this list, collecting their items into two new lists.
+	fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+impl Unzip for End {
+	type Left = End;
+	type Right = End;
+
+	fn unzip(self) -> (End, End) {
+		(End, End)
+	}
+}
+
+impl<A, B, R: Unzip> Unzip for List<(A, B), R> {
+	type Left = List<A, R::Left>;
+	type Right = List<B, R::Right>;
+
+	fn unzip(self) -> (Self::Left, Self::Right) {
+		let (a, b) = self.head;
+		let (left, right) = self.rest.unzip();
+		(
+			List {
+				head: a,
+				rest: left,
+			},
+			List {
+				head: b,
+				rest: right,
+			},
+		)
+	}
+}
+
+#[doc(hidden)]
+pub mod __ {
+	use core::fmt::{self, Debug, DebugList, Formatter};
+	use pretty_type_name::pretty_type_name;
+
+	/// Adds a list's items to a [`DebugList`], so that nested lists format flat.
+	pub trait DebugEntries {
+		fn debug_entries(&self, list: &mut DebugList<'_, '_>);
+	}
+
+	/// Formats a list as `[a, b, c]`, prefixed with its type name in alternate mode.
+	pub fn debug_list<L: DebugEntries>(list: &L, f: &mut Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			write!(f, "{} ", pretty_type_name::<L>())?;
+		}
+		let mut entries = f.debug_list();
+		list.debug_entries(&mut entries);
+		entries.finish()
+	}
+
+	/// Formats a list as `[a, b, c]` without type name, so that wrappers don't reveal their list's shape.
+	pub struct FlatList<'a, L>(pub &'a L);
+
+	impl<L: DebugEntries> Debug for FlatList<'_, L> {
+		fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+			let mut entries = f.debug_list();
+			self.0.debug_entries(&mut entries);
+			entries.finish()
+		}
+	}
+
+	/// Returns the [`CursorMut`](`crate::CursorMut`) of `R`, which a list only has if its rest has one.
+	///
+	/// # Panics
+	///
+	/// Iff `R` has no [`CursorMut`](`crate::CursorMut`).
+	#[must_use]
+	pub fn expect_cursor_mut<X: ?Sized, R: crate::IterateeMut<X>>() -> crate::CursorMut<X> {
+		R::cursor_mut_of().expect("missing `CursorMut`")
+	}
+
+	/// Prepending a field to a tuple.
+	///
+	/// This is implemented for tuples of up to 15 fields.
+	pub trait TuplePrepend<H> {
+		/// The tuple type with `H` as first field.
+		type Output;
+
+		/// Prepends `head` to `self`.
+		fn prepend(self, head: H) -> Self::Output;
+	}
+
+	/// Splitting off a tuple's first field.
+	///
+	/// This is implemented for tuples of 1 to 16 fields.
+	pub trait TupleSplitFirst {
+		/// The type of the first field.
+		type First;
+
+		/// The tuple type of the remaining fields.
+		type Rest;
+
+		/// Splits `self` into its first field and a tuple of the remaining ones.
+		fn split_first(self) -> (Self::First, Self::Rest);
+	}
+
+	macro_rules! impl_tuples {
+		() => {
+			impl_tuple!();
+		};
+		($first:ident $($rest:ident)*) => {
+			impl_tuple!($first $($rest)*);
+			impl_tuples!($($rest)*);
+		};
+	}
+
+	macro_rules! impl_tuple {
+		($($T:ident)*) => {
+			impl<H, $($T),*> TuplePrepend<H> for ($($T,)*) {
+				type Output = (H, $($T,)*);
+
+				#[allow(non_snake_case)]
+				fn prepend(self, head: H) -> Self::Output {
+					let ($($T,)*) = self;
+					(head, $($T,)*)
+				}
+			}
+
+			impl<H, $($T),*> TupleSplitFirst for (H, $($T,)*) {
+				type First = H;
+				type Rest = ($($T,)*);
+
+				#[allow(non_snake_case)]
+				fn split_first(self) -> (H, Self::Rest) {
+					let (head, $($T,)*) = self;
+					(head, ($($T,)*))
+				}
+			}
+		};
+	}
+
+	impl_tuples!(A B C D E F G I J K L M N O P);
+
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_types {
+		(
+			$(#[$listMeta:meta])*
+			$listVis:vis struct $List:ident[$($params:tt)*] where [$($bounds:tt)*]
+			{
+				$listFieldVis:vis head: $itemTy:ty,
+				..
+			},
+
+			$(#[$endMeta:meta])*
+			$endVis:vis struct $End:ident$(,)?
+		) => {
+			$(#[$listMeta])*
+			$listVis struct $List<$($params)* R>
+			where
+				$($bounds)*
+			{
+				/// The first item of the list.
+				$listFieldVis head: $itemTy,
+
+				/// The rest of the list.
+				$listFieldVis rest: R,
+			}
+
+			$(#[$endMeta])*
+			$endVis struct $End;
+		};
+	}
+	pub use custom_list_types;
+
+	/// Declares the list and end types and implements the standard traits on them,
+	/// minus the `#[no_standard_traits]` flag attribute that's munched first.
+	///
+	/// The item bounds are higher-ranked so that they don't count as trivial for lists without generics.
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_std {
+		(@flags $std:ident $metas:tt [#[no_standard_traits] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_std!(@flags no_standard_traits $metas [$($attrs)*] $($rest)*);
+		};
+		(@flags $std:ident [$($metas:tt)*] [#[$meta:meta] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_std!(@flags $std [$($metas)* #[$meta]] [$($attrs)*] $($rest)*);
+		};
+		(
+			@flags $std:ident [$($metas:tt)*] []
+			[$listVis:vis] struct $List:ident [$listFieldVis:vis] head: $itemTy:ty,
+			[$(#[$endMeta:meta])* [$endVis:vis] struct $End:ident]
+			[$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*]
+		) => {
+			$crate::__::custom_list_types!(
+				$($metas)* $listVis struct $List[$($params)*] where [$($bounds)*] {
+					$listFieldVis head: $itemTy,
+					..
+				},
+				$(#[$endMeta])* $endVis struct $End,
+			);
+
+			$crate::__::custom_list_std!(@impls $std [$List] [$End] [$($params)*] [$($args)*] [$($bounds)*] [$itemTy]);
+		};
+
+		(@impls no_standard_traits $($rest:tt)*) => {};
+		(@impls std [$List:ident] [$End:ident] [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*] [$itemTy:ty]) => {
+			impl ::core::fmt::Debug for $End {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					$crate::__::debug_list(self, f)
+				}
+			}
+
+			impl<$($params)* R: $crate::__::DebugEntries> ::core::fmt::Debug for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::fmt::Debug,
+			{
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					$crate::__::debug_list(self, f)
+				}
+			}
+
+			impl $crate::__::DebugEntries for $End {
+				fn debug_entries(&self, _: &mut ::core::fmt::DebugList<'_, '_>) {}
+			}
+
+			impl<$($params)* R: $crate::__::DebugEntries> $crate::__::DebugEntries for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::fmt::Debug,
+			{
+				fn debug_entries(&self, list: &mut ::core::fmt::DebugList<'_, '_>) {
+					list.entry(&self.head);
+					self.rest.debug_entries(list);
+				}
+			}
+
+			#[allow(clippy::expl_impl_clone_on_copy)]
+			impl ::core::clone::Clone for $End {
+				fn clone(&self) -> Self {
+					*self
+				}
+			}
+
+			// Not derived, since that would bound the generics rather than the items.
+			#[allow(clippy::expl_impl_clone_on_copy)]
+			impl<$($params)* R: ::core::clone::Clone> ::core::clone::Clone for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::clone::Clone,
+			{
+				fn clone(&self) -> Self {
+					$List {
+						head: self.head.clone(),
+						rest: self.rest.clone(),
+					}
+				}
+			}
+
+			impl ::core::marker::Copy for $End {}
+
+			impl<$($params)* R: ::core::marker::Copy> ::core::marker::Copy for $List<$($args)* R> where $($bounds)* for<'list> $itemTy: ::core::marker::Copy {}
+
+			impl ::core::cmp::PartialEq for $End {
+				fn eq(&self, _: &Self) -> bool {
+					true
+				}
+			}
+
+			impl<$($params)* R: ::core::cmp::PartialEq> ::core::cmp::PartialEq for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::cmp::PartialEq,
+			{
+				fn eq(&self, other: &Self) -> bool {
+					self.head == other.head && self.rest == other.rest
+				}
+			}
+
+			impl ::core::cmp::Eq for $End {}
+
+			impl<$($params)* R: ::core::cmp::Eq> ::core::cmp::Eq for $List<$($args)* R> where $($bounds)* for<'list> $itemTy: ::core::cmp::Eq {}
+
+			impl ::core::cmp::PartialOrd for $End {
+				fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+					::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+				}
+			}
+
+			/// Lexicographic, like tuples.
+			impl<$($params)* R: ::core::cmp::PartialOrd> ::core::cmp::PartialOrd for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::cmp::PartialOrd,
+			{
+				fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+					match self.head.partial_cmp(&other.head) {
+						::core::option::Option::Some(::core::cmp::Ordering::Equal) => self.rest.partial_cmp(&other.rest),
+						ordering => ordering,
+					}
+				}
+			}
+
+			impl ::core::cmp::Ord for $End {
+				fn cmp(&self, _: &Self) -> ::core::cmp::Ordering {
+					::core::cmp::Ordering::Equal
+				}
+			}
+
+			/// Lexicographic, like tuples.
+			impl<$($params)* R: ::core::cmp::Ord> ::core::cmp::Ord for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::cmp::Ord,
+			{
+				fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+					self.head.cmp(&other.head).then_with(|| self.rest.cmp(&other.rest))
+				}
+			}
+
+			impl ::core::hash::Hash for $End {
+				fn hash<H: ::core::hash::Hasher>(&self, _: &mut H) {}
+			}
+
+			impl<$($params)* R: ::core::hash::Hash> ::core::hash::Hash for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::hash::Hash,
+			{
+				fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+					self.head.hash(state);
+					self.rest.hash(state);
+				}
+			}
+
+			impl ::core::default::Default for $End {
+				fn default() -> Self {
+					$End
+				}
+			}
+
+			impl<$($params)* R: ::core::default::Default> ::core::default::Default for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: ::core::default::Default,
+			{
+				fn default() -> Self {
+					$List {
+						head: ::core::default::Default::default(),
+						rest: ::core::default::Default::default(),
+					}
+				}
+			}
+		};
+	}
+	pub use custom_list_std;
+
+	/// Declares and implements the builder trait of a custom list, minus the flag attributes
+	/// (`#[no_cons]`, `#[no_r_cons]` and `#[sealed]`/`#[sealed($seal)]`) that are munched first.
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_cons {
+		(@flags [$cons:ident $rCons:ident $sealed:tt] $metas:tt [#[no_cons] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@flags [no_cons $rCons $sealed] $metas [$($attrs)*] $($rest)*);
+		};
+		(@flags [$cons:ident $rCons:ident $sealed:tt] $metas:tt [#[no_r_cons] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@flags [$cons no_r_cons $sealed] $metas [$($attrs)*] $($rest)*);
+		};
+		(@flags [$cons:ident $rCons:ident $sealed:tt] $metas:tt [#[sealed] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@flags [$cons $rCons [__sealed]] $metas [$($attrs)*] $($rest)*);
+		};
+		(@flags [$cons:ident $rCons:ident $sealed:tt] $metas:tt [#[sealed($seal:ident)] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@flags [$cons $rCons [$seal]] $metas [$($attrs)*] $($rest)*);
+		};
+		(@flags $flags:tt [$($metas:tt)*] [#[$meta:meta] $($attrs:tt)*] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@flags $flags [$($metas)* #[$meta]] [$($attrs)*] $($rest)*);
+		};
+		(@flags $flags:tt $metas:tt [] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@emit $flags $metas $($rest)*);
+		};
+
+		(@emit [no_cons $rCons:ident $sealed:tt] $($rest:tt)*) => {};
+		(@emit [cons $rCons:ident open] $($rest:tt)*) => {
+			$crate::__::custom_list_cons!(@trait $rCons [] $($rest)*);
+		};
+		(@emit [cons $rCons:ident [$seal:ident]] $metas:tt [$List:ident] [$End:ident] [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*] $($rest:tt)*) => {
+			/// Private to the defining module, so that only the macro expansion can implement the builder trait.
+			#[allow(non_snake_case)]
+			mod $seal {
+				pub trait Sealed {}
+			}
+
+			impl $seal::Sealed for $End {}
+			impl<$($params)* R> $seal::Sealed for $List<$($args)* R> where $($bounds)* {}
+
+			$crate::__::custom_list_cons!(@trait $rCons [: $seal::Sealed] $metas [$List] [$End] [$($params)*] [$($args)*] [$($bounds)*] $($rest)*);
+		};
+
+		(
+			@trait $rCons:ident [$($supertraits:tt)*] [$($metas:tt)*]
+			[$List:ident] [$End:ident] [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*] [$($params01:tt)*] [$($args0:tt)*] [$($bounds01:tt)*]
+			[$itemTy:ty] [$($Wrapper:ident)?]
+			[$consVis:vis] trait $Cons:ident
+		) => {
+			$($metas)*
+			$consVis trait $Cons<$($params)*> $($supertraits)*
+			where
+				$($bounds)*
+			{
+				/// The result of a `cons` operation, with `T` prepended as head.
+				type Cons;
+
+				/// Transforms `self` by prepending `head`.
+				fn cons(self, head: $itemTy) -> Self::Cons;
+
+				$crate::__::custom_list_cons!(@r_cons_decl $rCons [$itemTy]);
+			}
+
+			impl<$($params)*> $Cons<$($args)*> for $End
+			where
+				$($bounds)*
+			{
+				type Cons = $List<$($args)* Self>;
+				fn cons(self, head: $itemTy) -> <Self as $Cons<$($args)*>>::Cons {
+					$List {
+						head,
+						rest: self,
+					}
+				}
+
+				$crate::__::custom_list_cons!(@r_cons_end $rCons [$List] [$($args)*] [$itemTy] [$Cons]);
+			}
+
+			impl<$($params01)* R: $Cons<$($args)*>> $Cons<$($args)*> for $List<$($args0)* R>
+			where
+				$($bounds01)*
+			{
+				type Cons = $List<$($args)* Self>;
+				fn cons(self, head: $itemTy) -> <Self as $Cons<$($args)*>>::Cons {
+					$List {
+						head,
+						rest: self,
+					}
+				}
+
+				$crate::__::custom_list_cons!(@r_cons_list $rCons [$List] [$($args)*] [$($args0)*] [$itemTy] [$Cons]);
+			}
+
+			$crate::__::custom_list_cons!(@wrapper $rCons [$($Wrapper)?] [$End] [$($supertraits)*] [$($params)*] [$($args)*] [$($bounds)*] [$itemTy] [$Cons]);
+		};
+
+		(@r_cons_decl no_r_cons $itemTy:tt) => {};
+		(@r_cons_decl r_cons [$itemTy:ty]) => {
+			/// The results of an `r_cons` operation, with `T` appended as tail.
+			type RCons;
+
+			/// Transforms `self` by appending `tail`.
+			fn r_cons(self, tail: $itemTy) -> Self::RCons;
+		};
+
+		(@r_cons_end no_r_cons $($rest:tt)*) => {};
+		(@r_cons_end r_cons [$List:ident] [$($args:tt)*] [$itemTy:ty] [$Cons:ident]) => {
+			type RCons = $List<$($args)* Self>;
+			fn r_cons(self, tail: $itemTy) -> <Self as $Cons<$($args)*>>::RCons {
+				$List {
+					head: tail,
+					rest: self,
+				}
+			}
+		};
+
+		(@r_cons_list no_r_cons $($rest:tt)*) => {};
+		(@r_cons_list r_cons [$List:ident] [$($args:tt)*] [$($args0:tt)*] [$itemTy:ty] [$Cons:ident]) => {
+			type RCons = $List<$($args0)* <R as $Cons<$($args)*>>::RCons>;
+			fn r_cons(self, tail: $itemTy) -> <Self as $Cons<$($args)*>>::RCons {
+				$List {
+					head: self.head,
+					rest: <R as $Cons<$($args)*>>::r_cons(self.rest, tail),
+				}
+			}
+		};
+
+		(@wrapper $rCons:ident [] $($rest:tt)*) => {};
+		(@wrapper $rCons:ident [$Wrapper:ident] [$End:ident] [: $seal:ident::Sealed] $($rest:tt)*) => {
+			impl<L> $seal::Sealed for $Wrapper<L> {}
+
+			$crate::__::custom_list_cons!(@wrapper $rCons [$Wrapper] [$End] [] $($rest)*);
+		};
+		(@wrapper $rCons:ident [$Wrapper:ident] [$End:ident] [] [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*] [$itemTy:ty] [$Cons:ident]) => {
+			impl<$($params)* L: $Cons<$($args)*>> $Cons<$($args)*> for $Wrapper<L>
+			where
+				$($bounds)*
+			{
+				type Cons = $Wrapper<<L as $Cons<$($args)*>>::Cons>;
+				fn cons(self, head: $itemTy) -> <Self as $Cons<$($args)*>>::Cons {
+					$Wrapper(self.0.cons(head))
+				}
+
+				$crate::__::custom_list_cons!(@r_cons_wrapper $rCons [$Wrapper] [$($args)*] [$itemTy] [$Cons]);
+			}
+		};
+
+		(@r_cons_wrapper no_r_cons $($rest:tt)*) => {};
+		(@r_cons_wrapper r_cons [$Wrapper:ident] [$($args:tt)*] [$itemTy:ty] [$Cons:ident]) => {
+			type RCons = $Wrapper<<L as $Cons<$($args)*>>::RCons>;
+			fn r_cons(self, tail: $itemTy) -> <Self as $Cons<$($args)*>>::RCons {
+				$Wrapper(self.0.r_cons(tail))
+			}
+		};
+
+		(
+			$List:tt $End:tt $params:tt $args:tt $bounds:tt $params01:tt $args0:tt $bounds01:tt $itemTy:tt $Wrapper:tt
+			[$($attrs:tt)*] $($trait:tt)*
+		) => {
+			$crate::__::custom_list_cons!(
+				@flags [cons r_cons open] [] [$($attrs)*]
+				$List $End $params $args $bounds $params01 $args0 $bounds01 $itemTy $Wrapper $($trait)*
+			);
+		};
+	}
+	pub use custom_list_cons;
+
+	/// Declares and implements the opaque wrapper of a custom list, if requested.
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_wrapper {
+		([$End:ident] []) => {};
+		([$End:ident] [$(#[$wrapperMeta:meta])* [$wrapperVis:vis] struct $Wrapper:ident]) => {
+			$(#[$wrapperMeta])*
+			#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+			#[repr(transparent)]
+			$wrapperVis struct $Wrapper<L = $End>(L);
+
+			impl<L: $crate::__::DebugEntries> ::core::fmt::Debug for $Wrapper<L> {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					f.debug_tuple(::core::stringify!($Wrapper))
+						.field(&$crate::__::FlatList(&self.0))
+						.finish()
+				}
+			}
+
+			impl $Wrapper {
+				/// Creates a new empty list.
+				#[must_use]
+				pub const fn new() -> Self {
+					Self($End)
+				}
+			}
+
+			impl<L: $crate::Len> $crate::Len for $Wrapper<L> {
+				const LEN: usize = L::LEN;
+			}
+
+			impl<X: ?Sized, L: $crate::StaticIter<X>> $crate::StaticIter<X> for $Wrapper<L> {
+				fn try_for_each_ref<E>(
+					&self,
+					on_each: impl ::core::ops::FnMut(&X) -> ::core::result::Result<(), E>,
+				) -> ::core::result::Result<(), E> {
+					self.0.try_for_each_ref(on_each)
+				}
+			}
+
+			impl<X: ?Sized, L: $crate::StaticIterMut<X>> $crate::StaticIterMut<X> for $Wrapper<L> {
+				fn try_for_each_mut<E>(
+					&mut self,
+					on_each: impl ::core::ops::FnMut(&mut X) -> ::core::result::Result<(), E>,
+				) -> ::core::result::Result<(), E> {
+					self.0.try_for_each_mut(on_each)
+				}
+			}
+
+			impl<X, L: $crate::StaticIntoIter<X>> $crate::StaticIntoIter<X> for $Wrapper<L> {
+				fn try_for_each<E>(
+					self,
+					on_each: impl ::core::ops::FnMut(X) -> ::core::result::Result<(), E>,
+				) -> ::core::result::Result<(), E> {
+					self.0.try_for_each(on_each)
+				}
+			}
+
+			// Sound since `$Wrapper` implements exactly the marker traits that `L` implements.
+			unsafe impl<X: ?Sized, L: $crate::Iteratee<X>> $crate::Iteratee<X> for $Wrapper<L> {
+				fn head_rest(&self) -> (::core::option::Option<&X>, &dyn $crate::Iteratee<X>) {
+					self.0.head_rest()
+				}
+
+				fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+					self.0.size_hint()
+				}
+
+				fn init_last(&self, len: usize) -> ::core::option::Option<&X> {
+					self.0.init_last(len)
+				}
+			}
+
+			// Sound since `$Wrapper` implements exactly the marker traits that `L` implements.
+			unsafe impl<X: ?Sized, L: $crate::IterateeMut<X>> $crate::IterateeMut<X> for $Wrapper<L> {
+				fn head_rest_mut(&mut self) -> (::core::option::Option<&mut X>, &mut dyn $crate::IterateeMut<X>) {
+					self.0.head_rest_mut()
+				}
+
+				fn as_iteratee(&self) -> &dyn $crate::Iteratee<X> {
+					self.0.as_iteratee()
+				}
+
+				fn cursor_mut(&self) -> ::core::option::Option<$crate::CursorMut<X>> {
+					<Self as $crate::IterateeMut<X>>::cursor_mut_of()
+				}
+
+				// Sound since `$Wrapper` is `#[repr(transparent)]`.
+				fn cursor_mut_of() -> ::core::option::Option<$crate::CursorMut<X>> {
+					L::cursor_mut_of()
+				}
+			}
+		};
+	}
+	pub use custom_list_wrapper;
+
+	#[cfg(feature = "serde")]
+	pub use serde;
+
+	/// Serializes a list's items into a tuple, so that nested lists serialize flat.
+	#[cfg(feature = "serde")]
+	pub trait SerializeItems {
+		fn serialize_items<X: serde::ser::SerializeTuple>(
+			&self,
+			tuple: &mut X,
+		) -> Result<(), X::Error>;
+	}
+
+	/// Deserializes a list's items from a sequence, starting at item `index` of the whole list.
+	#[cfg(feature = "serde")]
+	pub trait DeserializeItems<'de>: Sized {
+		fn deserialize_items<X: serde::de::SeqAccess<'de>>(
+			seq: &mut X,
+			index: usize,
+			expected: &dyn serde::de::Expected,
+		) -> Result<Self, X::Error>;
+	}
+
+	#[cfg(feature = "serde")]
+	pub fn serialize<L: crate::Len + SerializeItems, X: serde::Serializer>(
+		list: &L,
+		serializer: X,
+	) -> Result<X::Ok, X::Error> {
+		use serde::ser::SerializeTuple;
+
+		let mut tuple = serializer.serialize_tuple(L::LEN)?;
+		list.serialize_items(&mut tuple)?;
+		tuple.end()
+	}
+
+	#[cfg(feature = "serde")]
+	pub fn deserialize<'de, L: crate::Len + DeserializeItems<'de>, X: serde::Deserializer<'de>>(
+		deserializer: X,
+	) -> Result<L, X::Error> {
+		deserializer.deserialize_tuple(L::LEN, ListVisitor(core::marker::PhantomData))
+	}
+
+	#[cfg(feature = "serde")]
+	struct ListVisitor<L>(core::marker::PhantomData<fn() -> L>);
+
+	#[cfg(feature = "serde")]
+	impl<'de, L: crate::Len + DeserializeItems<'de>> serde::de::Visitor<'de> for ListVisitor<L> {
+		type Value = L;
+
+		fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+			write!(formatter, "a list of length {}", L::LEN)
+		}
+
+		fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<L, A::Error> {
+			use serde::de::{Error, IgnoredAny};
+
+			let list = L::deserialize_items(&mut seq, 0, &self)?;
+
+			let mut len = L::LEN;
+			while seq.next_element::<IgnoredAny>()?.is_some() {
+				len += 1;
+			}
+			if len == L::LEN {
+				Ok(list)
+			} else {
+				Err(A::Error::invalid_length(len, &self))
+			}
+		}
+	}
+
+	/// Implements [`serde::Serialize`] and [`serde::Deserialize`] for a custom list as fixed-length tuple.
+	#[cfg(feature = "serde")]
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_serde {
+		(
+			[$List:ident] [$End:ident] [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*] [$itemTy:ty]
+			[$($Wrapper:ident)?]
+		) => {
+			impl $crate::__::SerializeItems for $End {
+				fn serialize_items<X: $crate::__::serde::ser::SerializeTuple>(
+					&self,
+					_: &mut X,
+				) -> ::core::result::Result<(), X::Error> {
+					::core::result::Result::Ok(())
+				}
+			}
+
+			impl<$($params)* R: $crate::__::SerializeItems> $crate::__::SerializeItems for $List<$($args)* R>
+			where
+				$($bounds)*
+				for<'list> $itemTy: $crate::__::serde::Serialize,
+			{
+				fn serialize_items<X: $crate::__::serde::ser::SerializeTuple>(
+					&self,
+					tuple: &mut X,
+				) -> ::core::result::Result<(), X::Error> {
+					tuple.serialize_element(&self.head)?;
+					self.rest.serialize_items(tuple)
+				}
+			}
+
+			impl<'list> $crate::__::DeserializeItems<'list> for $End {
+				fn deserialize_items<X: $crate::__::serde::de::SeqAccess<'list>>(
+					_: &mut X,
+					_: usize,
+					_: &dyn $crate::__::serde::de::Expected,
+				) -> ::core::result::Result<Self, X::Error> {
+					::core::result::Result::Ok($End)
+				}
+			}
+
+			impl<'list, $($params)* R: $crate::__::DeserializeItems<'list>> $crate::__::DeserializeItems<'list> for $List<$($args)* R>
+			where
+				$($bounds)*
+				$itemTy: $crate::__::serde::Deserialize<'list>,
+			{
+				fn deserialize_items<X: $crate::__::serde::de::SeqAccess<'list>>(
+					seq: &mut X,
+					index: usize,
+					expected: &dyn $crate::__::serde::de::Expected,
+				) -> ::core::result::Result<Self, X::Error> {
+					let head = seq.next_element()?.ok_or_else(|| {
+						<X::Error as $crate::__::serde::de::Error>::invalid_length(index, expected)
+					})?;
+					let rest = R::deserialize_items(seq, index + 1, expected)?;
+					::core::result::Result::Ok($List { head, rest })
+				}
+			}
+
+			impl $crate::__::serde::Serialize for $End {
+				fn serialize<X: $crate::__::serde::Serializer>(
+					&self,
+					serializer: X,
+				) -> ::core::result::Result<X::Ok, X::Error> {
+					$crate::__::serialize(self, serializer)
+				}
+			}
+
+			impl<$($params)* R> $crate::__::serde::Serialize for $List<$($args)* R>
+			where
+				$($bounds)*
+				Self: $crate::Len + $crate::__::SerializeItems,
+			{
+				fn serialize<X: $crate::__::serde::Serializer>(
+					&self,
+					serializer: X,
+				) -> ::core::result::Result<X::Ok, X::Error> {
+					$crate::__::serialize(self, serializer)
+				}
+			}
+
+			impl<'list> $crate::__::serde::Deserialize<'list> for $End {
+				fn deserialize<X: $crate::__::serde::Deserializer<'list>>(
+					deserializer: X,
+				) -> ::core::result::Result<Self, X::Error> {
+					$crate::__::deserialize(deserializer)
+				}
+			}
+
+			impl<'list, $($params)* R> $crate::__::serde::Deserialize<'list> for $List<$($args)* R>
+			where
+				$($bounds)*
+				Self: $crate::Len + $crate::__::DeserializeItems<'list>,
+			{
+				fn deserialize<X: $crate::__::serde::Deserializer<'list>>(
+					deserializer: X,
+				) -> ::core::result::Result<Self, X::Error> {
+					$crate::__::deserialize(deserializer)
+				}
+			}
+
+			$(
+				impl<L: $crate::__::serde::Serialize> $crate::__::serde::Serialize for $Wrapper<L> {
+					fn serialize<X: $crate::__::serde::Serializer>(
+						&self,
+						serializer: X,
+					) -> ::core::result::Result<X::Ok, X::Error> {
+						self.0.serialize(serializer)
+					}
+				}
+
+				impl<'list, L: $crate::__::serde::Deserialize<'list>> $crate::__::serde::Deserialize<'list> for $Wrapper<L> {
+					fn deserialize<X: $crate::__::serde::Deserializer<'list>>(
+						deserializer: X,
+					) -> ::core::result::Result<Self, X::Error> {
+						L::deserialize(deserializer).map(Self)
+					}
+				}
+			)?
+		};
+	}
+
+	#[cfg(not(feature = "serde"))]
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! custom_list_serde {
+		($($input:tt)*) => {};
+	}
+	pub use custom_list_serde;
+
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! list_expr {
+		([$($List:tt)*][$($End:tt)*]) => {
+			$($End)*
+		};
+		([$($List:tt)*][$($End:tt)*] .. $rest:expr) => {
+			$rest
+		};
+		([$($List:tt)*][$($End:tt)*] $head:expr $(, $($tail:tt)*)?) => {
+			$($List)* {
+				head: $head,
+				rest: $crate::__::list_expr!([$($List)*][$($End)*] $($($tail)*)?),
+			}
+		};
+	}
+	pub use list_expr;
+
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! list_type {
+		([$($List:tt)*][$($End:tt)*]) => {
+			$($End)*
+		};
+		([$($List:tt)*][$($End:tt)*] .. $Rest:ty) => {
+			$Rest
+		};
+		([$($List:tt)*][$($End:tt)*] $Head:ty $(, $($tail:tt)*)?) => {
+			$($List)*<$Head, $crate::__::list_type!([$($List)*][$($End)*] $($($tail)*)?)>
+		};
+	}
+	pub use list_type;
+
+	#[doc(hidden)]
+	#[macro_export]
+	macro_rules! list_pattern {
+		([$($List:tt)*][$($End:tt)*]) => {
+			$($End)*
+		};
+		([$($List:tt)*][$($End:tt)*] ..) => {
+			_
+		};
+		([$($List:tt)*][$($End:tt)*] .. $rest:pat) => {
+			$rest
+		};
+		([$($List:tt)*][$($End:tt)*] $head:pat $(, $($tail:tt)*)?) => {
+			$($List)* {
+				head: $head,
+				rest: $crate::__::list_pattern!([$($List)*][$($End)*] $($($tail)*)?),
+			}
+		};
+	}
+	pub use list_pattern;
+}
+
+/// Creates and implements a custom cons list.
+///
+/// If a trailing `struct $Wrapper` is given, an opaque newtype `$Wrapper<L = $End>(L)` is generated too.
+/// It exposes iteration, length and the builder trait of the wrapped list `L`, but not its fields.
+/// Its [`Debug`](`core::fmt::Debug`) output is the flat `$Wrapper([a, b, c])`, without the type names of `L`.
+///
+/// The builder trait accepts these flag attributes:
+///
+/// - `#[no_cons]` omits the trait and its implementations entirely.
+/// - `#[no_r_cons]` omits `RCons` and `r_cons`.
+/// - `#[sealed]` prevents implementations of the trait outside of the macro expansion.
+///   The seal is a trait in a private module `__sealed` next to the list, so only the defining crate can name it.
+///   Use `#[sealed(name)]` to choose another module name, for example for several sealed lists in one module.
+///
+///   ```compile_fail
+///   mod lists {
+///       geode::custom_list!(
+///           pub struct Sealed {
+///               pub head: u8,
+///               ..
+///           },
+///           pub struct SealedEnd,
+///           #[sealed]
+///           pub trait SealedCons,
+///       );
+///   }
+///
+///   struct Outsider;
+///   impl lists::__sealed::Sealed for Outsider {}
+///   ```
+///
+/// The list and its end implement [`Debug`](`core::fmt::Debug`), [`Clone`], [`Copy`], [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`],
+/// [`Hash`](`core::hash::Hash`) and [`Default`] wherever all items do, with lexicographic ordering and `[a, b, c]` debug output.
+/// Put `#[no_standard_traits]` on the list to omit these implementations, for example to derive or implement them yourself instead.
+///
+/// With the `derive` feature, `geode::derive::list` generates the same from a struct declaration,
+/// with support for bounds, lifetimes, `const` generics and `where` clauses.
+///
+/// With the `serde` feature, the list, its end and the wrapper (de)serialize as fixed-length tuple.
+///
+/// The generic parameter names `'list`, `Acc`, `E`, `F`, `H`, `I`, `L`, `N`, `R`, `R0`, `Rhs`, `Target`, `Tuple` and `X`
+/// are used by the generated implementations and can't be used in `[$($generics)*][$($generics0)*]`.
+#[macro_export]
+macro_rules! custom_list {
+	(
+		$(#[$($listAttr:tt)*])*
+		$listVis:vis struct $List:ident
+			$([$($generics:tt)*][$($generics0:tt)*])?
+			$(where [$($constraints:tt)*][$($constraints0:tt)*])?
+		{
+			$listFieldVis:vis head: $itemTy:ty,
+			..
+		},
+
+		$(#[$endMeta:meta])*
+		$endVis:vis struct $End:ident,
+
+		$(#[$($consAttr:tt)*])*
+		$consVis:vis trait $Cons:ident
+		$(,
+			$(#[$wrapperMeta:meta])*
+			$wrapperVis:vis struct $Wrapper:ident
+		)?
+		$(,)?
+	) => {
+		$crate::custom_list!(@core
+			list: [$(#[$($listAttr)*])* [$listVis] struct $List [$listFieldVis] head: $itemTy],
+			end: [$(#[$endMeta])* [$endVis] struct $End],
+			cons: [$(#[$($consAttr)*])*] [$consVis] trait $Cons,
+			wrapper: [$($(#[$wrapperMeta])* [$wrapperVis] struct $Wrapper)?],
+			generics: [$($($generics)*,)?] [$($($generics)*,)?] [$($($constraints)*,)?],
+			generics0: [$($($generics)*, $($generics0)*,)?] [$($($generics0)*,)?] [$($($constraints)*, $($constraints0)*,)?],
+		);
+	};
+
+	// Normalized form, also emitted by `geode-derive`.
+	// `params`, `args` and `bounds` are comma-terminated (or empty) and describe a list node's generics.
+	// The second set describes a node with renamed generics nested in the first, with `params` and `bounds` merged.
+	(@core
+		list: [$(#[$($listAttr:tt)*])* [$listVis:vis] struct $List:ident [$listFieldVis:vis] head: $itemTy:ty],
+		end: [$(#[$endMeta:meta])* [$endVis:vis] struct $End:ident],
+		cons: [$($consAttrs:tt)*] [$consVis:vis] trait $Cons:ident,
+		wrapper: [$($(#[$wrapperMeta:meta])* [$wrapperVis:vis] struct $Wrapper:ident)?],
+		generics: [$($params:tt)*] [$($args:tt)*] [$($bounds:tt)*],
+		generics0: [$($params01:tt)*] [$($args0:tt)*] [$($bounds01:tt)*]$(,)?
+	) => {
+		$crate::__::custom_list_std!(
+			@flags std [] [$(#[$($listAttr)*])*]
+			[$listVis] struct $List [$listFieldVis] head: $itemTy,
+			[$(#[$endMeta])* [$endVis] struct $End]
+			[$($params)*] [$($args)*] [$($bounds)*]
+		);
+
+		$crate::__::custom_list_cons!(
+			[$List] [$End] [$($params)*] [$($args)*] [$($bounds)*] [$($params01)*] [$($args0)*] [$($bounds01)*] [$itemTy] [$($Wrapper)?]
+			[$($consAttrs)*] [$consVis] trait $Cons
+		);
+
+		$crate::__::custom_list_wrapper!([$End] [$($(#[$wrapperMeta])* [$wrapperVis] struct $Wrapper)?]);
+
+		$crate::__::custom_list_serde!(
+			[$List] [$End] [$($params)*] [$($args)*] [$($bounds)*] [$itemTy] [$($Wrapper)?]
+		);
+
+		impl<X: ?Sized> $crate::StaticIter<X> for $End {
+			fn try_for_each_ref<E>(
+				&self,
+				_: impl ::core::ops::FnMut(&X) -> ::core::result::Result<(), E>,
+			) -> ::core::result::Result<(), E> {
+				Ok(())
+			}
+		}
+
+		impl<X: ?Sized> $crate::StaticIterMut<X> for $End {
+			fn try_for_each_mut<E>(
+				&mut self,
+				_: impl ::core::ops::FnMut(&mut X) -> ::core::result::Result<(), E>,
+			) -> ::core::result::Result<(), E> {
+				Ok(())
+			}
+		}
+
+		impl<X> $crate::StaticIntoIter<X> for $End {
 			fn try_for_each<E>(
 				self,
 				_: impl ::core::ops::FnMut(X) -> ::core::result::Result<(), E>,
-			) -> ::core::result::Result<(), E> where X: Sized {
+			) -> ::core::result::Result<(), E> {
 				Ok(())
 			}
-			fn try_for_each_ref<E>(
-				&self,
-				_: impl ::core::ops::FnMut(&X) -> ::core::result::Result<(), E>,
-			) -> ::core::result::Result<(), E> {
-				Ok(())
+		}
+
+		impl<$($params)* X: ?Sized, R: $crate::StaticIter<X>> $crate::StaticIter<X> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: ::core::borrow::Borrow<X>,
+		{
+			fn try_for_each_ref<E>(
+				&self,
+				mut on_each: impl ::core::ops::FnMut(&X) -> ::core::result::Result<(), E>,
+			) -> ::core::result::Result<(), E> {
+				on_each(::core::borrow::Borrow::borrow(&self.head))?;
+				self.rest.try_for_each_ref(on_each)
+			}
+		}
+
+		impl<$($params)* X: ?Sized, R: $crate::StaticIterMut<X>> $crate::StaticIterMut<X> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: ::core::borrow::BorrowMut<X>,
+		{
+			fn try_for_each_mut<E>(
+				&mut self,
+				mut on_each: impl ::core::ops::FnMut(&mut X) -> ::core::result::Result<(), E>,
+			) -> ::core::result::Result<(), E> {
+				on_each(::core::borrow::BorrowMut::borrow_mut(&mut self.head))?;
+				self.rest.try_for_each_mut(on_each)
+			}
+		}
+
+		impl<$($params)* X, R: $crate::StaticIntoIter<X>> $crate::StaticIntoIter<X> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: ::core::convert::Into<X>,
+		{
+			fn try_for_each<E>(
+				self,
+				mut on_each: impl ::core::ops::FnMut(X) -> ::core::result::Result<(), E>,
+			) -> ::core::result::Result<(), E> {
+				on_each(self.head.into())?;
+				self.rest.try_for_each(on_each)
+			}
+		}
+
+		impl<$($params)* R> $crate::Selector<$itemTy, $crate::Here> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Remainder = R;
+
+			fn get(&self) -> &$itemTy {
+				&self.head
+			}
+
+			fn get_mut(&mut self) -> &mut $itemTy {
+				&mut self.head
+			}
+
+			fn pluck(self) -> ($itemTy, R) {
+				(self.head, self.rest)
+			}
+		}
+
+		impl<$($params)* R: $crate::Selector<X, I>, X, I> $crate::Selector<X, $crate::There<I>> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Remainder = $List<$($args)* R::Remainder>;
+
+			fn get(&self) -> &X {
+				self.rest.get()
+			}
+
+			fn get_mut(&mut self) -> &mut X {
+				self.rest.get_mut()
+			}
+
+			fn pluck(self) -> (X, Self::Remainder) {
+				let (item, rest) = self.rest.pluck();
+				(item, $List {
+					head: self.head,
+					rest,
+				})
+			}
+		}
+
+		impl $crate::UniqueTypes<$crate::End> for $End {}
+
+		impl<$($params)* R: $crate::NotContains<$itemTy, I> + $crate::UniqueTypes<N>, I, N> $crate::UniqueTypes<$crate::List<I, N>> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{}
+
+		impl<Rhs> $crate::IsSubsetOf<Rhs, $crate::End> for $End {
+			type Complement = Rhs;
+		}
+
+		impl<$($params)* R, Rhs: $crate::Selector<$itemTy, I>, I, N> $crate::IsSubsetOf<Rhs, $crate::List<I, N>> for $List<$($args)* R>
+		where
+			$($bounds)*
+			R: $crate::IsSubsetOf<Rhs::Remainder, N>,
+		{
+			type Complement = R::Complement;
+		}
+
+		impl<Rhs> $crate::IsDisjointFrom<Rhs, $crate::End> for $End {}
+
+		impl<$($params)* R: $crate::IsDisjointFrom<Rhs, N>, Rhs: $crate::NotContains<$itemTy, I>, I, N> $crate::IsDisjointFrom<Rhs, $crate::List<I, N>> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{}
+
+		impl<$($params)* R> $crate::AtIndex<$crate::Here> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = $itemTy;
+			type Remainder = R;
+
+			fn at_index(&self) -> &$itemTy {
+				&self.head
+			}
+
+			fn at_index_mut(&mut self) -> &mut $itemTy {
+				&mut self.head
+			}
+
+			fn take_at_index(self) -> ($itemTy, R) {
+				(self.head, self.rest)
+			}
+		}
+
+		impl<$($params)* R: $crate::AtIndex<I>, I> $crate::AtIndex<$crate::There<I>> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = R::Output;
+			type Remainder = $List<$($args)* R::Remainder>;
+
+			fn at_index(&self) -> &R::Output {
+				self.rest.at_index()
+			}
+
+			fn at_index_mut(&mut self) -> &mut R::Output {
+				self.rest.at_index_mut()
+			}
+
+			fn take_at_index(self) -> (R::Output, Self::Remainder) {
+				let (item, rest) = self.rest.take_at_index();
+				(item, $List {
+					head: self.head,
+					rest,
+				})
+			}
+		}
+
+		impl $crate::Concat<$End> for $End {
+			type Output = $End;
+
+			fn concat(self, rhs: $End) -> $End {
+				rhs
+			}
+		}
+
+		impl<$($params)* R> $crate::Concat<$List<$($args)* R>> for $End
+		where
+			$($bounds)*
+		{
+			type Output = $List<$($args)* R>;
+
+			fn concat(self, rhs: $List<$($args)* R>) -> Self::Output {
+				rhs
+			}
+		}
+
+		impl<$($params)* R: $crate::Concat<Rhs>, Rhs> $crate::Concat<Rhs> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = $List<$($args)* R::Output>;
+
+			fn concat(self, rhs: Rhs) -> Self::Output {
+				$List {
+					head: self.head,
+					rest: self.rest.concat(rhs),
+				}
+			}
+		}
+
+		impl<Rhs> ::core::ops::Add<Rhs> for $End
+		where
+			Self: $crate::Concat<Rhs>,
+		{
+			type Output = <Self as $crate::Concat<Rhs>>::Output;
+
+			fn add(self, rhs: Rhs) -> Self::Output {
+				$crate::Concat::concat(self, rhs)
+			}
+		}
+
+		impl<$($params)* R, Rhs> ::core::ops::Add<Rhs> for $List<$($args)* R>
+		where
+			$($bounds)*
+			Self: $crate::Concat<Rhs>,
+		{
+			type Output = <Self as $crate::Concat<Rhs>>::Output;
+
+			fn add(self, rhs: Rhs) -> Self::Output {
+				$crate::Concat::concat(self, rhs)
+			}
+		}
+
+		impl<Acc> $crate::ReverseOnto<Acc> for $End {
+			type Output = Acc;
+
+			fn reverse_onto(self, acc: Acc) -> Acc {
+				acc
+			}
+		}
+
+		impl<$($params)* R: $crate::ReverseOnto<$List<$($args)* Acc>>, Acc> $crate::ReverseOnto<Acc> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = R::Output;
+
+			fn reverse_onto(self, acc: Acc) -> Self::Output {
+				self.rest.reverse_onto($List {
+					head: self.head,
+					rest: acc,
+				})
+			}
+		}
+
+		impl $crate::Reverse for $End {
+			type Output = Self;
+
+			fn reverse(self) -> Self {
+				self
+			}
+		}
+
+		impl<$($params)* R> $crate::Reverse for $List<$($args)* R>
+		where
+			$($bounds)*
+			Self: $crate::ReverseOnto<$End>,
+		{
+			type Output = <Self as $crate::ReverseOnto<$End>>::Output;
+
+			fn reverse(self) -> Self::Output {
+				$crate::ReverseOnto::reverse_onto(self, $End)
+			}
+		}
+
+		impl $crate::SplitAtIndex<$crate::Here> for $End {
+			type Prefix = $End;
+			type Suffix = Self;
+
+			fn split_at_index(self) -> ($End, Self) {
+				($End, self)
+			}
+		}
+
+		impl<$($params)* R> $crate::SplitAtIndex<$crate::Here> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Prefix = $End;
+			type Suffix = Self;
+
+			fn split_at_index(self) -> ($End, Self) {
+				($End, self)
+			}
+		}
+
+		impl<$($params)* R: $crate::SplitAtIndex<I>, I> $crate::SplitAtIndex<$crate::There<I>> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Prefix = $List<$($args)* R::Prefix>;
+			type Suffix = R::Suffix;
+
+			fn split_at_index(self) -> (Self::Prefix, Self::Suffix) {
+				let (prefix, suffix) = self.rest.split_at_index();
+				($List {
+					head: self.head,
+					rest: prefix,
+				}, suffix)
+			}
+		}
+
+		impl<$($params)* R> $crate::First for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = $itemTy;
+
+			fn first(&self) -> &$itemTy {
+				&self.head
+			}
+
+			fn first_mut(&mut self) -> &mut $itemTy {
+				&mut self.head
+			}
+		}
+
+		impl<$($params)*> $crate::Last for $List<$($args)* $End>
+		where
+			$($bounds)*
+		{
+			type Output = $itemTy;
+
+			fn last(&self) -> &$itemTy {
+				&self.head
+			}
+
+			fn last_mut(&mut self) -> &mut $itemTy {
+				&mut self.head
 			}
-			fn try_for_each_mut<E>(
-				&mut self,
-				_: impl ::core::ops::FnMut(&mut X) -> ::core::result::Result<(), E>,
-			) -> ::core::result::Result<(), E> {
-				Ok(())
+		}
+
+		impl<$($params01)* R> $crate::Last for $List<$($args)* $List<$($args0)* R>>
+		where
+			$($bounds01)*
+			$List<$($args0)* R>: $crate::Last,
+		{
+			type Output = <$List<$($args0)* R> as $crate::Last>::Output;
+
+			fn last(&self) -> &Self::Output {
+				self.rest.last()
+			}
+
+			fn last_mut(&mut self) -> &mut Self::Output {
+				self.rest.last_mut()
+			}
+		}
+
+		impl<$($params)*> $crate::Init for $List<$($args)* $End>
+		where
+			$($bounds)*
+		{
+			type Output = $End;
+			type Last = Self;
+
+			fn split_last(self) -> ($End, Self) {
+				($End, self)
+			}
+		}
+
+		impl<$($params01)* R> $crate::Init for $List<$($args)* $List<$($args0)* R>>
+		where
+			$($bounds01)*
+			$List<$($args0)* R>: $crate::Init,
+		{
+			type Output = $List<$($args)* <$List<$($args0)* R> as $crate::Init>::Output>;
+			type Last = <$List<$($args0)* R> as $crate::Init>::Last;
+
+			fn split_last(self) -> (Self::Output, Self::Last) {
+				let (init, last) = self.rest.split_last();
+				($List {
+					head: self.head,
+					rest: init,
+				}, last)
+			}
+		}
+
+		impl $crate::RotateLeft for $End {
+			type Output = Self;
+
+			fn rotate_left(self) -> Self {
+				self
+			}
+		}
+
+		impl<$($params)* R: $crate::Concat<$List<$($args)* $End>>> $crate::RotateLeft for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = R::Output;
+
+			fn rotate_left(self) -> Self::Output {
+				self.rest.concat($List {
+					head: self.head,
+					rest: $End,
+				})
+			}
+		}
+
+		impl $crate::RotateRight for $End {
+			type Output = Self;
+
+			fn rotate_right(self) -> Self {
+				self
+			}
+		}
+
+		impl<$($params)* R> $crate::RotateRight for $List<$($args)* R>
+		where
+			$($bounds)*
+			Self: $crate::Init,
+			<Self as $crate::Init>::Last: $crate::Concat<<Self as $crate::Init>::Output>,
+		{
+			type Output = <<Self as $crate::Init>::Last as $crate::Concat<<Self as $crate::Init>::Output>>::Output;
+
+			fn rotate_right(self) -> Self::Output {
+				let (init, last) = $crate::Init::split_last(self);
+				$crate::Concat::concat(last, init)
+			}
+		}
+
+		impl $crate::IntoTuple for $End {
+			type Tuple = ();
+
+			fn into_tuple(self) -> Self::Tuple {}
+		}
+
+		impl<$($params)* R: $crate::IntoTuple> $crate::IntoTuple for $List<$($args)* R>
+		where
+			$($bounds)*
+			R::Tuple: $crate::__::TuplePrepend<$itemTy>,
+		{
+			type Tuple = <R::Tuple as $crate::__::TuplePrepend<$itemTy>>::Output;
+
+			fn into_tuple(self) -> Self::Tuple {
+				$crate::__::TuplePrepend::prepend(self.rest.into_tuple(), self.head)
+			}
+		}
+
+		impl<'list> $crate::AsTupleRefs<'list> for $End {
+			type Refs = ();
+
+			fn as_tuple_refs(&'list self) -> Self::Refs {}
+		}
+
+		impl<'list, $($params)* R: $crate::AsTupleRefs<'list>> $crate::AsTupleRefs<'list> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: 'list,
+			R::Refs: $crate::__::TuplePrepend<&'list $itemTy>,
+		{
+			type Refs = <R::Refs as $crate::__::TuplePrepend<&'list $itemTy>>::Output;
+
+			fn as_tuple_refs(&'list self) -> Self::Refs {
+				$crate::__::TuplePrepend::prepend(self.rest.as_tuple_refs(), &self.head)
+			}
+		}
+
+		impl ::core::convert::From<()> for $End {
+			fn from((): ()) -> Self {
+				$End
+			}
+		}
+
+		impl<$($params)* R, Tuple> ::core::convert::From<Tuple> for $List<$($args)* R>
+		where
+			$($bounds)*
+			Tuple: $crate::__::TupleSplitFirst<First = $itemTy>,
+			R: ::core::convert::From<Tuple::Rest>,
+		{
+			fn from(tuple: Tuple) -> Self {
+				let (head, rest) = tuple.split_first();
+				$List {
+					head,
+					rest: rest.into(),
+				}
+			}
+		}
+
+		impl<F> $crate::Map<F> for $End {
+			type Output = $crate::End;
+
+			fn map(self, _: F) -> $crate::End {
+				$crate::End
+			}
+		}
+
+		impl<$($params)* R: $crate::Map<F>, F: $crate::Func<$itemTy>> $crate::Map<F> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = $crate::List<F::Output, R::Output>;
+
+			fn map(self, mut f: F) -> Self::Output {
+				$crate::List {
+					head: f.call(self.head),
+					rest: self.rest.map(f),
+				}
+			}
+		}
+
+		impl<'list, F> $crate::MapRef<'list, F> for $End {
+			type Output = $crate::End;
+
+			fn map_ref(&'list self, _: F) -> $crate::End {
+				$crate::End
+			}
+		}
+
+		impl<'list, $($params)* R: $crate::MapRef<'list, F>, F: $crate::Func<&'list $itemTy>> $crate::MapRef<'list, F> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: 'list,
+		{
+			type Output = $crate::List<F::Output, R::Output>;
+
+			fn map_ref(&'list self, mut f: F) -> Self::Output {
+				$crate::List {
+					head: f.call(&self.head),
+					rest: self.rest.map_ref(f),
+				}
+			}
+		}
+
+		impl<'list, F> $crate::MapMut<'list, F> for $End {
+			type Output = $crate::End;
+
+			fn map_mut(&'list mut self, _: F) -> $crate::End {
+				$crate::End
+			}
+		}
+
+		impl<'list, $($params)* R: $crate::MapMut<'list, F>, F: $crate::Func<&'list mut $itemTy>> $crate::MapMut<'list, F> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: 'list,
+		{
+			type Output = $crate::List<F::Output, R::Output>;
+
+			fn map_mut(&'list mut self, mut f: F) -> Self::Output {
+				$crate::List {
+					head: f.call(&mut self.head),
+					rest: self.rest.map_mut(f),
+				}
+			}
+		}
+
+		impl $crate::Zip<$End> for $End {
+			type Output = $crate::End;
+
+			fn zip(self, _: $End) -> $crate::End {
+				$crate::End
+			}
+		}
+
+		impl<$($params01)* R: $crate::Zip<R0>, R0> $crate::Zip<$List<$($args0)* R0>> for $List<$($args)* R>
+		where
+			$($bounds01)*
+		{
+			type Output = $crate::List<($itemTy, <$List<$($args0)* R0> as $crate::First>::Output), R::Output>;
+
+			fn zip(self, other: $List<$($args0)* R0>) -> Self::Output {
+				$crate::List {
+					head: (self.head, other.head),
+					rest: self.rest.zip(other.rest),
+				}
+			}
+		}
+
+		impl<$($params)* R> $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			/// Borrows the item of type `X`.
+			///
+			/// Its index `I` is inferred, see `geode::Selector`.
+			#[must_use]
+			pub fn get<X, I>(&self) -> &X
+			where
+				Self: $crate::Selector<X, I>,
+			{
+				$crate::Selector::get(self)
+			}
+
+			/// Mutably borrows the item of type `X`.
+			///
+			/// Its index `I` is inferred, see `geode::Selector`.
+			#[must_use]
+			pub fn get_mut<X, I>(&mut self) -> &mut X
+			where
+				Self: $crate::Selector<X, I>,
+			{
+				$crate::Selector::get_mut(self)
+			}
+
+			/// Moves the item of type `X` out of this list, returning it with the remaining list.
+			///
+			/// Its index `I` is inferred, see `geode::Selector`.
+			#[must_use]
+			pub fn pluck<X, I>(self) -> (X, <Self as $crate::Selector<X, I>>::Remainder)
+			where
+				Self: $crate::Selector<X, I>,
+			{
+				$crate::Selector::pluck(self)
+			}
+
+			/// Moves the items of `Target` out of this list, in `Target`'s order, returning them with the remaining list.
+			///
+			/// The item indices `I` are inferred, see `geode::Sculptor`.
+			#[must_use]
+			pub fn sculpt<Target, I>(self) -> (Target, <Self as $crate::Sculptor<Target, I>>::Remainder)
+			where
+				Self: $crate::Sculptor<Target, I>,
+			{
+				$crate::Sculptor::sculpt(self)
+			}
+
+			/// Moves the items of `Target` out of this list or, for those not found here, `other`.
+			///
+			/// `Target` must name exactly the item types of the union. The indices `I` are inferred, see `geode::UnionInto`.
+			#[must_use]
+			pub fn union_into<Rhs, Target, I>(self, other: Rhs) -> Target
+			where
+				Self: $crate::UnionInto<Rhs, Target, I>,
+			{
+				$crate::UnionInto::union_into(self, other)
+			}
+
+			/// Moves the items of `Target` out of this list, dropping the rest.
+			///
+			/// `Target` must name exactly the item types of the intersection with `other`. The indices `I` are inferred, see `geode::IntersectionInto`.
+			#[must_use]
+			pub fn intersection_into<Rhs, Target, I>(self, other: &Rhs) -> Target
+			where
+				Self: $crate::IntersectionInto<Rhs, Target, I>,
+			{
+				$crate::IntersectionInto::intersection_into(self, other)
+			}
+
+			/// Moves the items of `Target` out of this list, dropping the rest.
+			///
+			/// `Target` must name exactly the item types of the difference from `other`. The indices `I` are inferred, see `geode::DifferenceInto`.
+			#[must_use]
+			pub fn difference_into<Rhs, Target, I>(self, other: &Rhs) -> Target
+			where
+				Self: $crate::DifferenceInto<Rhs, Target, I>,
+			{
+				$crate::DifferenceInto::difference_into(self, other)
+			}
+
+			/// Borrows the item at index `N`.
+			///
+			/// Out-of-range indices fail to compile, see `geode::At`.
+			#[must_use]
+			pub fn at<const N: usize>(&self) -> &<Self as $crate::At<N>>::Output
+			where
+				Self: $crate::At<N>,
+			{
+				$crate::At::at(self)
+			}
+
+			/// Mutably borrows the item at index `N`.
+			///
+			/// Out-of-range indices fail to compile, see `geode::At`.
+			#[must_use]
+			pub fn at_mut<const N: usize>(&mut self) -> &mut <Self as $crate::At<N>>::Output
+			where
+				Self: $crate::At<N>,
+			{
+				$crate::At::at_mut(self)
+			}
+
+			/// Moves the item at index `N` out of this list, returning it with the remaining list.
+			///
+			/// Out-of-range indices fail to compile, see `geode::At`.
+			#[must_use]
+			pub fn take_at<const N: usize>(self) -> (<Self as $crate::At<N>>::Output, <Self as $crate::At<N>>::Remainder)
+			where
+				Self: $crate::At<N>,
+			{
+				$crate::At::take_at(self)
+			}
+
+			/// Splits this list after its first `N` items.
+			///
+			/// `N` may be equal to the list's length, see `geode::SplitAt`.
+			#[must_use]
+			pub fn split_at<const N: usize>(self) -> (<Self as $crate::SplitAt<N>>::Prefix, <Self as $crate::SplitAt<N>>::Suffix)
+			where
+				Self: $crate::SplitAt<N>,
+			{
+				$crate::SplitAt::split_at(self)
+			}
+		}
+
+		impl $crate::Len for $End {
+			const LEN: usize = 0;
+		}
+
+		impl<$($params)* R: $crate::Len> $crate::Len for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			const LEN: usize = R::LEN + 1;
+		}
+
+		impl<F, Acc> $crate::FoldLeft<F, Acc> for $End {
+			type Output = Acc;
+
+			fn foldl(self, _: F, acc: Acc) -> Acc {
+				acc
+			}
+		}
+
+		impl<$($params)* R: $crate::FoldLeft<F, F::Output>, F: $crate::Folder<Acc, $itemTy>, Acc> $crate::FoldLeft<F, Acc> for $List<$($args)* R>
+		where
+			$($bounds)*
+		{
+			type Output = R::Output;
+
+			fn foldl(self, mut folder: F, acc: Acc) -> Self::Output {
+				let acc = folder.fold(acc, self.head);
+				self.rest.foldl(folder, acc)
+			}
+		}
+
+		// Sound since `$End` has no fields, so it's its own rest.
+		unsafe impl<X: ?Sized> $crate::Iteratee<X> for $End {
+			fn head_rest(&self) -> (::core::option::Option<&X>, &dyn $crate::Iteratee<X>) {
+				(None, self)
+			}
+
+			fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+				(0, Some(0))
+			}
+
+			fn init_last(&self, _: usize) -> ::core::option::Option<&X> {
+				None
+			}
+		}
+
+		unsafe impl<X: ?Sized> $crate::IterateeMut<X> for $End {
+			fn head_rest_mut(&mut self) -> (::core::option::Option<&mut X>, &mut dyn $crate::IterateeMut<X>) {
+				(None, self)
+			}
+
+			fn as_iteratee(&self) -> &dyn $crate::Iteratee<X> {
+				self
+			}
+
+			fn cursor_mut(&self) -> ::core::option::Option<$crate::CursorMut<X>> {
+				<Self as $crate::IterateeMut<X>>::cursor_mut_of()
+			}
+
+			fn cursor_mut_of() -> ::core::option::Option<$crate::CursorMut<X>> {
+				Some($crate::CursorMut {
+					head_rest: |this| (None, this, $crate::__::expect_cursor_mut::<X, Self>()),
+					init_last: |_, _| None,
+					cursor: $crate::Cursor {
+						head_rest: |this| (None, this, $crate::__::expect_cursor_mut::<X, Self>().cursor),
+						init_last: |_, _| None,
+					},
+				})
+			}
+		}
+
+		// Sound since `$List` has no manual marker trait implementations,
+		// so each one it implements is implemented by `R` too.
+		unsafe impl<$($params)* X: ?Sized, R: $crate::Iteratee<X>> $crate::Iteratee<X> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: ::core::borrow::Borrow<X>,
+		{
+			fn head_rest(&self) -> (::core::option::Option<&X>, &dyn $crate::Iteratee<X>) {
+				(Some(::core::borrow::Borrow::borrow(&self.head)), &self.rest)
+			}
+
+			fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+				let (min, max) = self.rest.size_hint();
+				(min + 1, max.map(|max| max + 1))
+			}
+
+			fn init_last(&self, len: usize) -> ::core::option::Option<&X> {
+				match len {
+					0 => None,
+					1 => Some(::core::borrow::Borrow::borrow(&self.head)),
+					_ => self.rest.init_last(len - 1),
+				}
+			}
+		}
+
+		unsafe impl<$($params)* X: ?Sized, R: $crate::IterateeMut<X>> $crate::IterateeMut<X> for $List<$($args)* R>
+		where
+			$($bounds)*
+			$itemTy: ::core::borrow::BorrowMut<X>,
+		{
+			fn head_rest_mut(&mut self) -> (::core::option::Option<&mut X>, &mut dyn $crate::IterateeMut<X>) {
+				(Some(::core::borrow::BorrowMut::borrow_mut(&mut self.head)), &mut self.rest)
 			}
-  		}
-	};
 
-	// (
-	// 	$(#[$listMeta:meta])*
-	// 	$listVis:vis $List:ident $({$listFieldVis:vis})?,
+			fn as_iteratee(&self) -> &dyn $crate::Iteratee<X> {
+				self
+			}
 
-	// 	$(#[$endMeta:meta])*
-	// 	$endVis:vis $End:ident,
+			fn cursor_mut(&self) -> ::core::option::Option<$crate::CursorMut<X>> {
+				<Self as $crate::IterateeMut<X>>::cursor_mut_of()
+			}
 
-	// 	$(#[$consMeta:meta])*
-	// 	$consVis:vis $Cons:ident,
+			// Only the returned item is borrowed, everything else is reached through `addr_of!` and `addr_of_mut!`.
+			fn cursor_mut_of() -> ::core::option::Option<$crate::CursorMut<X>> {
+				// The cursor functions below rely on this one.
+				<R as $crate::IterateeMut<X>>::cursor_mut_of()?;
 
-	// 	$wrapperVis:vis $Wrapper:ident
-	// 	$(,)?
-	// ) => {
-	// 	compile_error!("TODO: Cons list with wrapper.")
-	// };
+				Some($crate::CursorMut {
+					head_rest: |this| unsafe {
+						let this = this.cast::<Self>();
+						let head: &mut X = ::core::borrow::BorrowMut::borrow_mut(&mut *::core::ptr::addr_of_mut!((*this).head));
+						(
+							Some(head),
+							::core::ptr::addr_of_mut!((*this).rest).cast(),
+							$crate::__::expect_cursor_mut::<X, R>(),
+						)
+					},
+					init_last: |this, len| unsafe {
+						let this = this.cast::<Self>();
+						match len {
+							0 => None,
+							1 => Some(::core::borrow::BorrowMut::borrow_mut(&mut *::core::ptr::addr_of_mut!((*this).head))),
+							_ => ($crate::__::expect_cursor_mut::<X, R>().init_last)(
+								::core::ptr::addr_of_mut!((*this).rest).cast(),
+								len - 1,
+							),
+						}
+					},
+					cursor: $crate::Cursor {
+						head_rest: |this| unsafe {
+							let this = this.cast::<Self>();
+							let head: &X = ::core::borrow::Borrow::borrow(&*::core::ptr::addr_of!((*this).head));
+							(
+								Some(head),
+								::core::ptr::addr_of!((*this).rest).cast(),
+								$crate::__::expect_cursor_mut::<X, R>().cursor,
+							)
+						},
+						init_last: |this, len| unsafe {
+							let this = this.cast::<Self>();
+							match len {
+								0 => None,
+								1 => Some(::core::borrow::Borrow::borrow(&*::core::ptr::addr_of!((*this).head))),
+								_ => ($crate::__::expect_cursor_mut::<X, R>().cursor.init_last)(
+									::core::ptr::addr_of!((*this).rest).cast(),
+									len - 1,
+								),
+							}
+						},
+					},
+				})
+			}
+		}
+	};
 }
 
 custom_list!(
 	/// A basic cons list with public implementation details.
 	///
-	/// Use [`Cons`] methods on [`End`] to start constructing a [`List`].
+	/// Use [`list!`] or [`Cons`] methods on [`End`] to construct a [`List`](struct@List).
+	///
+	/// Its type can be named with [`List!`] and destructured with [`list_pat!`].
 	pub struct List[T][T0] {
 		pub head: T,
 		..
 	},
 
-	/// An empty rest for [`List`].
+	/// An empty rest for [`List`](struct@List).
 	pub struct End,
 
-	/// Builder functionality for [`List`] and [`End`].
+	/// Builder functionality for [`List`](struct@List) and [`End`].
 	pub trait Cons,
 );
+
+/// Creates a [`List`](struct@List) from a sequence of expressions.
+///
+/// `..rest` in last position uses `rest` as the remaining list instead of [`End`].
+///
+/// Prefix the items with `use MyList, MyEnd;` to create a list type generated by [`custom_list!`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{list, End, List};
+///
+/// let list: List<u8, List<&str, End>> = list![1, "two"];
+/// assert_eq!(list.head, 1);
+/// assert_eq!(list.rest.head, "two");
+///
+/// let longer = list![0.5, ..list];
+/// assert_eq!(longer.rest.head, 1);
+/// ```
+#[macro_export]
+macro_rules! list {
+	(use $($List:ident)::+, $($End:ident)::+; $($items:tt)*) => {
+		$crate::__::list_expr!([$($List)::+][$($End)::+] $($items)*)
+	};
+	($($items:tt)*) => {
+		$crate::__::list_expr!([$crate::List][$crate::End] $($items)*)
+	};
+}
+
+/// Names a [`List`](struct@List) type from a sequence of item types.
+///
+/// `..Rest` in last position uses `Rest` as the remaining list type instead of [`End`].
+///
+/// Prefix the items with `use MyList, MyEnd;` to name a list type generated by [`custom_list!`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{list, End, List};
+///
+/// let list: List![u8, &str] = list![1, "two"];
+/// let _: List<u8, List<&str, End>> = list;
+/// ```
+#[macro_export]
+macro_rules! List {
+	(use $($List:ident)::+, $($End:ident)::+; $($items:tt)*) => {
+		$crate::__::list_type!([$($List)::+][$($End)::+] $($items)*)
+	};
+	($($items:tt)*) => {
+		$crate::__::list_type!([$crate::List][$crate::End] $($items)*)
+	};
+}
+
+/// Destructures a [`List`](struct@List) in a pattern.
+///
+/// `..rest` in last position binds the remaining list to the pattern `rest`,
+/// and a plain `..` ignores it. Otherwise, the list must end after the given items.
+///
+/// Prefix the items with `use MyList, MyEnd;` to destructure a list type generated by [`custom_list!`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{list, list_pat};
+///
+/// let list_pat![a, ..] = list![1, "two"];
+/// assert_eq!(a, 1);
+///
+/// match list![1, "two", 3.0] {
+///     list_pat![0, ..] => unreachable!(),
+///     list_pat![_, b, ..rest] => {
+///         assert_eq!(b, "two");
+///         assert_eq!(rest.head, 3.0);
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! list_pat {
+	(use $($List:ident)::+, $($End:ident)::+; $($items:tt)*) => {
+		$crate::__::list_pattern!([$($List)::+][$($End)::+] $($items)*)
+	};
+	($($items:tt)*) => {
+		$crate::__::list_pattern!([$crate::List][$crate::End] $($items)*)
+	};
+}
+
+/// Statically asserts that no item type occurs more than once in each given list type.
+///
+/// Duplicates fail compilation with error [E0283] "type annotations needed", which notes
+/// the conflicting [`NotContains`] implementations for the duplicate item type
+/// and is required by a bound in `item_types_must_be_unique`:
+///
+/// ```text
+/// error[E0283]: type annotations needed
+///   |
+///   = note: multiple `impl`s satisfying `List<Position, geode::End>: NotContains<Position, _>` found in the `geode` crate:
+///           - impl<L, T, I> NotContains<T, Present<I>> for L
+///             where L: Contains<T, I>;
+///           - impl<L, T> NotContains<T, geode::private::Absent> for L;
+///   …
+/// note: required by a bound in `item_types_must_be_unique`
+/// ```
+///
+/// Types that aren't lists fail with error [E0277] instead, as they don't implement [`UniqueTypes`].
+///
+/// [E0283]: https://doc.rust-lang.org/error-index.html#E0283
+/// [E0277]: https://doc.rust-lang.org/error-index.html#E0277
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{assert_unique_types, List};
+///
+/// struct Position;
+/// struct Velocity;
+///
+/// assert_unique_types!(List![Position, Velocity]);
+/// ```
+///
+/// ```rust,compile_fail,E0283
+/// use geode::{assert_unique_types, List};
+///
+/// struct Position;
+///
+/// assert_unique_types!(List![Position, Position]);
+/// ```
+#[macro_export]
+macro_rules! assert_unique_types {
+	($($List:ty),+$(,)?) => {
+		const _: fn() = || {
+			fn item_types_must_be_unique<L: $crate::UniqueTypes<I>, I>() {}
+			$(item_types_must_be_unique::<$List, _>();)+
+		};
+	};
+}
+
+/// Names a [`Coproduct`](coproduct::Coproduct) type from a sequence of variant types.
+///
+/// `..Rest` in last position uses `Rest` as the remaining coproduct type instead of [`CoproductEnd`](coproduct::CoproductEnd).
+///
+/// # Example
+///
+/// ```rust
+/// use geode::{coproduct::{Coproduct, CoproductEnd}, Coproduct};
+///
+/// let value: Coproduct![u8, &str] = Coproduct::inject("two");
+/// let _: Coproduct<u8, Coproduct<&str, CoproductEnd>> = value;
+/// ```
+#[macro_export]
+macro_rules! Coproduct {
+	($($items:tt)*) => {
+		$crate::__::list_type!([$crate::coproduct::Coproduct][$crate::coproduct::CoproductEnd] $($items)*)
+	};
+}
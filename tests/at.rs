@@ -0,0 +1,41 @@
+use geode::{list, At, List};
+
+#[test]
+fn at() {
+	let mut list = list![1_u8, "two", 3.0];
+
+	assert_eq!(*list.at::<0>(), 1);
+	assert_eq!(*list.at::<1>(), "two");
+	*list.at_mut::<2>() += 0.5;
+	assert_eq!(list.rest.rest.head, 3.5);
+}
+
+#[test]
+fn take_at() {
+	let list = list![1_u8, "two", 3.0];
+
+	let (two, rest): (&str, List![u8, f64]) = list.take_at::<1>();
+	assert_eq!(two, "two");
+	assert_eq!((rest.head, rest.rest.head), (1, 3.0));
+}
+
+#[test]
+fn projection() {
+	fn last_of_three<L: At<2>>(list: &L) -> &<L as At<2>>::Output {
+		list.at()
+	}
+
+	let list = list![1_u8, "two", 3.0];
+	let last: &f64 = last_of_three(&list);
+	assert_eq!(*last, 3.0);
+}
+
+#[test]
+fn long() {
+	let list = list![
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+		25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+		48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+	];
+	assert_eq!(*list.at::<63>(), 63);
+}
@@ -0,0 +1,24 @@
+use geode::{list, Concat, End, List, StaticIter};
+
+#[test]
+fn add() {
+	let list: List![u8, &str, f64, char] = list![1_u8, "two"] + list![3.0, '4'];
+	assert_eq!(*list.at::<2>(), 3.0);
+	assert_eq!(*list.at::<3>(), '4');
+
+	let _: List![u8] = End + list![1_u8];
+	let _: List![u8] = list![1_u8] + End;
+	let _: End = End + End;
+}
+
+#[test]
+fn concat() {
+	let list = list![1, 2].concat(list![3]).concat(list![4, 5]);
+	assert_eq!(
+		list.fold_ref(vec![], |mut items, item: &i32| {
+			items.push(*item);
+			items
+		}),
+		[1, 2, 3, 4, 5]
+	);
+}
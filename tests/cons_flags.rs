@@ -0,0 +1,107 @@
+use geode::{Len, StaticIter};
+
+mod lists {
+	geode::custom_list!(
+		/// A list that can only be prepended to.
+		pub struct StackList[T][T0] {
+			pub head: T,
+			..
+		},
+
+		/// The end of a [`StackList`].
+		pub struct StackEnd,
+
+		/// Prepending to [`StackList`] and [`StackEnd`].
+		#[no_r_cons]
+		pub trait StackCons,
+	);
+
+	geode::custom_list!(
+		/// A list without builder trait.
+		pub struct FixedList[T][T0] {
+			pub head: T,
+			..
+		},
+
+		/// The end of a [`FixedList`].
+		pub struct FixedEnd,
+
+		#[no_cons]
+		pub trait FixedCons,
+	);
+
+	geode::custom_list!(
+		/// A list that can't be extended by other types.
+		pub struct SealedList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`SealedList`].
+		pub struct SealedEnd,
+
+		/// Builder functionality for [`SealedList`] and [`SealedEnd`].
+		#[sealed]
+		#[must_use]
+		pub trait SealedCons,
+
+		/// An opaque [`SealedList`].
+		pub struct Sealed,
+	);
+
+	geode::custom_list!(
+		/// A second sealed list in the same module.
+		pub struct NamedList[T][T0] {
+			pub head: T,
+			..
+		},
+
+		/// The end of a [`NamedList`].
+		pub struct NamedEnd,
+
+		/// Builder functionality for [`NamedList`] and [`NamedEnd`].
+		#[sealed(named_seal)]
+		pub trait NamedCons,
+	);
+}
+
+use lists::{
+	FixedEnd, FixedList, NamedCons, NamedEnd, Sealed, SealedCons, SealedEnd, StackCons, StackEnd,
+};
+
+#[test]
+fn no_r_cons() {
+	let list = StackEnd.cons(2).cons(1);
+	assert_eq!(
+		list.fold_ref(vec![], |mut items, item: &i32| {
+			items.push(*item);
+			items
+		}),
+		[1, 2]
+	);
+}
+
+#[test]
+fn no_cons() {
+	let list = geode::list!(use FixedList, FixedEnd; 1, 2, 3);
+	assert_eq!(list.len(), 3);
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 6);
+}
+
+#[test]
+fn sealed() {
+	let list = SealedEnd
+		.cons(Box::new(2))
+		.r_cons(Box::new(3))
+		.cons(Box::new(1));
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum * 10 + item), 123);
+
+	let wrapped = Sealed::new().cons(Box::new(1)).r_cons(Box::new(2));
+	assert_eq!(wrapped.len(), 2);
+}
+
+#[test]
+fn sealed_named() {
+	let list = NamedEnd.cons(1).r_cons(2);
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum * 10 + item), 12);
+}
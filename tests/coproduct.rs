@@ -0,0 +1,60 @@
+use geode::{
+	coproduct::{Coproduct, CoproductEnd, Embedder},
+	list, Coproduct,
+};
+
+type Value = Coproduct![u8, &'static str, bool];
+
+#[test]
+fn inject_get_take() {
+	let mut value: Value = Coproduct::inject("two");
+	assert_eq!(value, Coproduct::Inr(Coproduct::Inl("two")));
+
+	assert_eq!(value.get::<u8, _>(), None);
+	assert_eq!(value.get::<&str, _>(), Some(&"two"));
+	*value.get_mut::<&str, _>().unwrap() = "three";
+
+	assert_eq!(value.take::<bool, _>(), None);
+	assert_eq!(value.take::<&str, _>(), Some("three"));
+}
+
+#[test]
+fn uninject() {
+	let value: Value = Coproduct::inject(true);
+
+	let rest: Coproduct![u8, bool] = value.uninject::<&str, _>().unwrap_err();
+	assert_eq!(rest, Coproduct::inject(true));
+	assert_eq!(rest.uninject::<bool, _>(), Ok(true));
+
+	let value: Value = Coproduct::inject(1_u8);
+	assert_eq!(value.uninject::<u8, _>(), Ok(1));
+}
+
+#[test]
+fn embed() {
+	let narrow: Coproduct![bool, u8] = Coproduct::inject(2_u8);
+	let wide: Value = narrow.embed();
+	assert_eq!(wide.take::<u8, _>(), Some(2));
+
+	let narrow: Coproduct![bool, u8] = Coproduct::inject(false);
+	let wide: Value = narrow.embed();
+	assert_eq!(wide.take::<bool, _>(), Some(false));
+
+	let empty: Option<CoproductEnd> = None;
+	let _: Option<Value> = empty.map(Embedder::embed);
+}
+
+#[test]
+fn fold() {
+	let describe = |value: Value| {
+		value.fold(list![
+			|n: u8| format!("number {n}"),
+			|s: &str| format!("text {s}"),
+			|b: bool| format!("flag {b}"),
+		])
+	};
+
+	assert_eq!(describe(Coproduct::inject(1_u8)), "number 1");
+	assert_eq!(describe(Coproduct::inject("two")), "text two");
+	assert_eq!(describe(Coproduct::inject(true)), "flag true");
+}
@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+mod lists {
+	use std::rc::Rc;
+
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct BoxEnd,
+
+		/// Builder functionality for [`BoxList`] and [`BoxEnd`].
+		pub trait BoxCons,
+	);
+
+	geode::custom_list!(
+		/// A list of shared items.
+		pub struct RcList[T][T0] {
+			pub head: Rc<T>,
+			..
+		},
+
+		/// The end of an [`RcList`].
+		pub struct RcEnd,
+
+		/// Builder functionality for [`RcList`] and [`RcEnd`].
+		pub trait RcCons,
+	);
+
+	geode::custom_list!(
+		/// A list of numbers.
+		pub struct NumberList {
+			pub head: u32,
+			..
+		},
+
+		/// The end of a [`NumberList`].
+		pub struct NumberEnd,
+
+		/// Builder functionality for [`NumberList`] and [`NumberEnd`].
+		pub trait NumberCons,
+	);
+}
+
+use geode::{Len, StaticIter};
+use lists::{BoxCons, BoxEnd, NumberCons, NumberEnd, RcCons, RcEnd};
+
+#[test]
+fn side_by_side() {
+	let boxed = BoxEnd.cons(Box::new(2)).cons(Box::new(1));
+	let shared = RcEnd.cons(Rc::new(2)).cons(Rc::new(1)).r_cons(Rc::new(3));
+	let numbers = NumberEnd.cons(1).r_cons(2);
+
+	assert_eq!(boxed.fold_ref(0, |sum, item: &i32| sum + item), 3);
+	assert_eq!(shared.fold_ref(0, |sum, item: &Rc<i32>| sum + **item), 6);
+	assert_eq!(numbers.fold_ref(0, |sum, item: &u32| sum + item), 3);
+	assert_eq!((boxed.len(), shared.len(), numbers.len()), (2, 3, 2));
+}
+
+#[test]
+fn literals() {
+	use lists::{BoxList, RcList};
+
+	let boxed: geode::List!(use BoxList, BoxEnd; i32, i32) =
+		geode::list!(use BoxList, BoxEnd; Box::new(1), Box::new(2));
+	let geode::list_pat!(use RcList, RcEnd; first, ..rest) =
+		geode::list!(use RcList, RcEnd; Rc::new(1));
+	assert_eq!(*boxed.rest.head, 2);
+	assert_eq!(*first, 1);
+	assert_eq!(rest, RcEnd);
+}
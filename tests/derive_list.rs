@@ -0,0 +1,110 @@
+#![cfg(feature = "derive")]
+
+use core::fmt::Debug;
+use geode::{Init, Last, Len, StaticIter};
+
+mod lists {
+	use core::fmt::Debug;
+
+	/// Plain items.
+	#[geode::derive::list]
+	pub struct Plain<T> {
+		pub head: T,
+	}
+
+	/// Borrowed items.
+	#[geode::derive::list]
+	pub struct Borrowed<'a, T: ?Sized>
+	where
+		T: 'a + Debug,
+	{
+		pub head: &'a T,
+	}
+
+	/// Arrays of one length.
+	#[geode::derive::list(end = Nil, cons = Push, sealed, no_r_cons)]
+	pub struct Arrays<const M: usize> {
+		pub head: [u8; M],
+	}
+
+	/// Items with manual standard traits.
+	#[geode::derive::list(no_standard_traits)]
+	#[derive(Debug)]
+	pub struct Manual<T> {
+		pub head: T,
+	}
+
+	/// An opaque list.
+	#[geode::derive::list(wrapper = Opaque)]
+	pub struct Hidden<T> {
+		pub head: Box<T>,
+	}
+
+	/// Parameters named like a trait and an associated type.
+	#[geode::derive::list]
+	pub struct Sums<Output, Debug>
+	where
+		Debug: core::fmt::Debug + core::ops::Add<Output = Output>,
+		<Debug as core::ops::Add>::Output: Copy,
+	{
+		pub head: (Debug, Output),
+	}
+}
+
+use lists::{
+	Arrays, Borrowed, BorrowedCons, BorrowedEnd, HiddenCons, Manual, Nil, Opaque, PlainCons,
+	PlainEnd, Push, SumsCons, SumsEnd,
+};
+
+#[test]
+fn plain() {
+	let list = PlainEnd.cons(2).cons(1).r_cons(3);
+	assert_eq!(list.len(), 3);
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 6);
+	assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+	assert_eq!(*list.last(), 3);
+}
+
+#[test]
+fn borrowed() {
+	let one = String::from("one");
+	let list: Borrowed<'_, dyn Debug, Borrowed<'_, dyn Debug, BorrowedEnd>> =
+		BorrowedEnd.cons(&2 as &dyn Debug).cons(&one as &dyn Debug);
+
+	let mut seen = vec![];
+	list.for_each_ref(|item: &&dyn Debug| seen.push(format!("{:?}", item)));
+	assert_eq!(seen, [r#""one""#, "2"]);
+}
+
+#[test]
+fn const_generic() {
+	let list = Nil.cons([3, 4]).cons([1, 2]);
+	let (init, last) = list.split_last();
+	assert_eq!(last.head, [3, 4]);
+	assert!(matches!(
+		init,
+		Arrays {
+			head: [1, 2],
+			rest: Nil,
+		}
+	));
+}
+
+#[test]
+fn wrapper() {
+	let list = Opaque::new().cons(Box::new(1)).r_cons(Box::new(2));
+	assert_eq!(list.len(), 2);
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 3);
+}
+
+#[test]
+fn no_standard_traits() {
+	let list = Manual { head: 1, rest: () };
+	assert_eq!(format!("{:?}", list), "Manual { head: 1, rest: () }");
+}
+
+#[test]
+fn shadowing_names() {
+	let list = SumsEnd.cons((2, 4)).cons((1, 2));
+	assert_eq!(list.fold_ref(0, |sum, (a, b): &(i32, i32)| sum + a + b), 9);
+}
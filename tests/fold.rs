@@ -0,0 +1,51 @@
+use geode::{list, End, FoldLeft, FoldRight, Folder};
+
+/// Builds a nested description, changing the accumulator type with each step.
+struct Nest;
+impl<Acc> Folder<Acc, u8> for Nest {
+	type Output = (Acc, &'static str);
+	fn fold(&mut self, acc: Acc, _: u8) -> Self::Output {
+		(acc, "u8")
+	}
+}
+impl<Acc> Folder<Acc, &'static str> for Nest {
+	type Output = (Acc, usize);
+	fn fold(&mut self, acc: Acc, item: &'static str) -> Self::Output {
+		(acc, item.len())
+	}
+}
+
+#[test]
+fn foldl() {
+	let ((((), a), b), c) = list![1_u8, "three", 2_u8].foldl(Nest, ());
+	assert_eq!((a, b, c), ("u8", 5, "u8"));
+}
+
+#[test]
+fn foldr() {
+	let ((((), a), b), c) = list![1_u8, "three", 2_u8].foldr(Nest, ());
+	assert_eq!((a, b, c), ("u8", 5, "u8"));
+
+	let (((), d), e) = list!["four", 1_u8].foldr(Nest, ());
+	assert_eq!((d, e), ("u8", 4));
+}
+
+#[test]
+fn closure() {
+	let order = list![1, 2, 3].foldl(
+		|acc: String, item: i32| format!("{}{}", acc, item),
+		String::new(),
+	);
+	assert_eq!(order, "123");
+
+	let order = list![1, 2, 3].foldr(
+		|acc: String, item: i32| format!("{}{}", acc, item),
+		String::new(),
+	);
+	assert_eq!(order, "321");
+}
+
+#[test]
+fn empty() {
+	assert_eq!(End.foldl(Nest, 5), 5);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "derive")]
+
+use geode::{labels, End, Field, Generic, LabelledGeneric, List};
+
+#[derive(Debug, PartialEq, Generic, LabelledGeneric)]
+struct Named<T> {
+	id: u32,
+	r#type: T,
+}
+
+#[derive(Debug, PartialEq, Generic, LabelledGeneric)]
+struct Tuple(u8, &'static str);
+
+#[derive(Debug, PartialEq, Generic, LabelledGeneric)]
+struct Unit;
+
+#[test]
+fn generic() {
+	let repr: List![u32, char] = Named { id: 1, r#type: 'a' }.into_repr();
+	assert_eq!(repr, geode::list![1, 'a']);
+	assert_eq!(Named::from_repr(repr), Named { id: 1, r#type: 'a' });
+
+	let repr: List![u8, &str] = Tuple(1, "one").into_repr();
+	assert_eq!(Tuple::from_repr(repr), Tuple(1, "one"));
+
+	let repr: End = Unit.into_repr();
+	assert_eq!(Unit::from_repr(repr), Unit);
+}
+
+type Id = List![labels::i, labels::d];
+type Type = List![labels::t, labels::y, labels::p, labels::e];
+
+#[test]
+fn labelled() {
+	let repr: List![Field<Id, u32>, Field<Type, char>] =
+		Named { id: 1, r#type: 'a' }.into_labelled_repr();
+	assert_eq!((repr.head.name, repr.rest.head.name), ("id", "type"));
+	assert_eq!(
+		Named::from_labelled_repr(repr),
+		Named { id: 1, r#type: 'a' }
+	);
+
+	let repr: List![Field<List![labels::_0], u8>, Field<List![labels::_1], &str>] =
+		Tuple(1, "one").into_labelled_repr();
+	assert_eq!(repr.rest.head.name, "1");
+	assert_eq!(Tuple::from_labelled_repr(repr), Tuple(1, "one"));
+}
+
+#[derive(Debug, PartialEq, LabelledGeneric)]
+struct Renamed {
+	id: u32,
+	r#type: char,
+}
+
+#[test]
+fn same_labels() {
+	let repr = Named { id: 1, r#type: 'a' }.into_labelled_repr();
+	assert_eq!(
+		Renamed::from_labelled_repr(repr),
+		Renamed { id: 1, r#type: 'a' }
+	);
+}
@@ -0,0 +1,276 @@
+use geode::{
+	iterators::{Iter, IterMut},
+	Cons, Cursor, CursorMut, DynIter, DynIterMut, End, Iteratee, IterateeMut, List,
+};
+use std::rc::Rc;
+
+#[test]
+fn iter() {
+	let list = End.cons(3).cons(2).cons(1);
+
+	let iter = Iter::new(&list as &dyn Iteratee<i32>);
+	assert_eq!(iter.size_hint(), (3, Some(3)));
+	assert_eq!(iter.copied().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn iter_mut() {
+	let mut list = End.cons(3).cons(2).cons(1);
+
+	{
+		let mut iter = IterMut::new(&mut list as &mut dyn IterateeMut<i32>);
+		assert_eq!(iter.size_hint(), (3, Some(3)));
+		*iter.nth(1).unwrap() = 20;
+		assert_eq!(iter.size_hint(), (1, Some(1)));
+	}
+
+	for item in IterMut::new(&mut list as &mut dyn IterateeMut<i32>) {
+		*item += 1;
+	}
+	assert_eq!(
+		Iter::new(&list as &dyn Iteratee<i32>)
+			.copied()
+			.collect::<Vec<_>>(),
+		[2, 21, 4]
+	);
+}
+
+#[test]
+fn send_sync() {
+	fn assert_send_sync<T: Send + Sync>(value: T) -> T {
+		value
+	}
+
+	let mut list = End.cons("two").cons("one");
+
+	let iter = assert_send_sync(Iter::new(&list as &(dyn Iteratee<&str> + Send + Sync)));
+	assert_eq!(iter.copied().collect::<Vec<_>>(), ["one", "two"]);
+
+	let iter_mut = assert_send_sync(IterMut::new(
+		&mut list as &mut (dyn IterateeMut<&str> + Send + Sync),
+	));
+	assert_eq!(Iter::from(iter_mut).count(), 2);
+}
+
+#[test]
+fn empty() {
+	let iter = Iter::new(&End as &dyn Iteratee<()>);
+	assert_eq!(iter.size_hint(), (0, Some(0)));
+	assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn dyn_iter() {
+	fn sum_send(iter: Iter<&(dyn Iteratee<i32> + Send)>) -> i32 {
+		iter.sum()
+	}
+
+	let mut list = End.cons(3).cons(2).cons(1);
+	assert_eq!(sum_send(list.dyn_iter()), 6);
+
+	let iter_mut: IterMut<&mut (dyn IterateeMut<i32> + Send + Sync)> = list.dyn_iter_mut();
+	for item in iter_mut {
+		*item *= 2;
+	}
+
+	let iter: Iter<&dyn Iteratee<i32>> = list.dyn_iter();
+	assert_eq!(iter.copied().collect::<Vec<_>>(), [2, 4, 6]);
+}
+
+#[test]
+fn dyn_iter_local() {
+	let list = End.cons(Rc::new(2)).cons(Rc::new(1));
+
+	let iter: Iter<&dyn Iteratee<Rc<i32>>> = list.dyn_iter();
+	assert_eq!(iter.map(|item| **item).sum::<i32>(), 3);
+}
+
+#[test]
+fn rev() {
+	let mut list = End.cons(3).cons(2).cons(1);
+
+	let iter = Iter::new(&list as &dyn Iteratee<i32>);
+	assert_eq!(iter.rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+	for (i, item) in IterMut::new(&mut list as &mut dyn IterateeMut<i32>)
+		.rev()
+		.enumerate()
+	{
+		*item += i as i32 * 10;
+	}
+	assert_eq!(
+		Iter::new(&list as &dyn Iteratee<i32>)
+			.copied()
+			.collect::<Vec<_>>(),
+		[21, 12, 3]
+	);
+}
+
+#[test]
+fn both_ends() {
+	let mut list = End.cons(4).cons(3).cons(2).cons(1);
+
+	let mut iter = IterMut::new(&mut list as &mut dyn IterateeMut<i32>);
+	let last = iter.next_back().unwrap();
+	let first = iter.next().unwrap();
+	*last *= 10;
+	*first *= 10;
+	assert_eq!(iter.size_hint(), (2, Some(2)));
+	assert_eq!(iter.fork_shared().copied().collect::<Vec<_>>(), [2, 3]);
+
+	let third = iter.next_back().unwrap();
+	let second = iter.next().unwrap();
+	*third *= 10;
+	*second *= 10;
+	*last += 1;
+	assert_eq!(iter.next(), None);
+	assert_eq!(iter.next_back(), None);
+
+	assert_eq!(
+		Iter::new(&list as &dyn Iteratee<i32>)
+			.copied()
+			.collect::<Vec<_>>(),
+		[10, 20, 30, 41]
+	);
+}
+
+/// Claims two items, but returns its only one from both ends.
+struct Overlapping(i32);
+
+unsafe impl Iteratee<i32> for Overlapping {
+	fn head_rest(&self) -> (Option<&i32>, &dyn Iteratee<i32>) {
+		(Some(&self.0), self)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(2, Some(2))
+	}
+}
+
+unsafe impl IterateeMut<i32> for Overlapping {
+	fn head_rest_mut(&mut self) -> (Option<&mut i32>, &mut dyn IterateeMut<i32>) {
+		unreachable!("only iterated through its cursor")
+	}
+
+	fn as_iteratee(&self) -> &dyn Iteratee<i32> {
+		self
+	}
+
+	fn cursor_mut(&self) -> Option<CursorMut<i32>> {
+		Self::cursor_mut_of()
+	}
+
+	fn cursor_mut_of() -> Option<CursorMut<i32>> {
+		Some(CursorMut {
+			head_rest: |this| (Some(this.cast()), this, Self::cursor_mut_of().unwrap()),
+			init_last: |this, len| (len > 0).then(|| this.cast()),
+			cursor: Cursor {
+				head_rest: |this| {
+					(
+						Some(this.cast()),
+						this,
+						Self::cursor_mut_of().unwrap().cursor,
+					)
+				},
+				init_last: |this, len| (len > 0).then(|| this.cast()),
+			},
+		})
+	}
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the front and back of `IterMut` crossed")]
+fn crossing_ends() {
+	let mut overlapping = Overlapping(1);
+	IterMut::new(&mut overlapping as &mut dyn IterateeMut<i32>).next_back();
+}
+
+/// A linked list that only implements the reference-based protocol.
+struct Chain(Option<(i32, Box<Chain>)>);
+
+impl Chain {
+	fn new(items: &[i32]) -> Self {
+		Self(
+			items
+				.split_first()
+				.map(|(head, rest)| (*head, Box::new(Self::new(rest)))),
+		)
+	}
+}
+
+unsafe impl Iteratee<i32> for Chain {
+	fn head_rest(&self) -> (Option<&i32>, &dyn Iteratee<i32>) {
+		match &self.0 {
+			Some((head, rest)) => (Some(head), &**rest),
+			None => (None, self),
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.0 {
+			Some((_, rest)) => {
+				let len = rest.size_hint().0 + 1;
+				(len, Some(len))
+			}
+			None => (0, Some(0)),
+		}
+	}
+}
+
+unsafe impl IterateeMut<i32> for Chain {
+	fn head_rest_mut(&mut self) -> (Option<&mut i32>, &mut dyn IterateeMut<i32>) {
+		if self.0.is_none() {
+			return (None, self);
+		}
+		let (head, rest) = self.0.as_mut().unwrap();
+		(Some(head), &mut **rest)
+	}
+
+	fn as_iteratee(&self) -> &dyn Iteratee<i32> {
+		self
+	}
+}
+
+#[test]
+fn without_cursor() {
+	let mut chain = Chain::new(&[1, 2, 3]);
+
+	let iter = Iter::new(&chain as &dyn Iteratee<i32>);
+	assert_eq!(iter.size_hint(), (3, Some(3)));
+	assert_eq!(iter.clone().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+	assert_eq!(iter.copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+	let mut iter_mut = IterMut::new(&mut chain as &mut dyn IterateeMut<i32>);
+	*iter_mut.next().unwrap() *= 10;
+	assert_eq!(iter_mut.fork_shared().copied().collect::<Vec<_>>(), [2, 3]);
+	*iter_mut.next().unwrap() *= 10;
+	*iter_mut.next_back().unwrap() *= 10;
+	assert_eq!(iter_mut.next_back(), None);
+
+	let mut list = List {
+		head: 0,
+		rest: chain,
+	};
+	assert!(IterateeMut::<i32>::cursor_mut(&list).is_none());
+	assert_eq!(
+		Iter::new(&list as &dyn Iteratee<i32>)
+			.rev()
+			.copied()
+			.collect::<Vec<_>>(),
+		[30, 20, 10, 0]
+	);
+	assert_eq!(
+		Iter::from(IterMut::new(&mut list as &mut dyn IterateeMut<i32>)).count(),
+		4
+	);
+}
+
+#[test]
+#[should_panic(
+	expected = "`IterMut` can only iterate from the back of `IterateeMut`s with a `cursor_mut`"
+)]
+fn without_cursor_rev() {
+	let mut chain = Chain::new(&[1, 2]);
+	IterMut::new(&mut chain as &mut dyn IterateeMut<i32>).next_back();
+}
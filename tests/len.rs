@@ -0,0 +1,28 @@
+use geode::{list, DynIter, End, Iteratee, Len, List};
+
+#[test]
+fn static_length() {
+	assert_eq!(<End as Len>::LEN, 0);
+	assert_eq!(<List![u8, &str, f64] as Len>::LEN, 3);
+
+	let buffer = [0_u8; <List![u8, u16] as Len>::LEN];
+	assert_eq!(buffer.len(), 2);
+}
+
+#[test]
+fn methods() {
+	let list = list![1, "two"];
+	assert_eq!(list.len(), 2);
+	assert!(!list.is_empty());
+	assert!(End.is_empty());
+}
+
+#[test]
+fn exact_size() {
+	let list = list![1, 2, 3];
+
+	let mut iter: geode::iterators::Iter<&dyn Iteratee<i32>> = list.dyn_iter();
+	assert_eq!(iter.len(), 3);
+	iter.next();
+	assert_eq!(iter.len(), 2);
+}
@@ -0,0 +1,67 @@
+use geode::{list, list_pat, End, List};
+
+#[test]
+fn expression() {
+	let list = list![1_u8, "two", 3.0];
+	assert_eq!(list.head, 1);
+	assert_eq!(list.rest.head, "two");
+	assert_eq!(list.rest.rest.head, 3.0);
+
+	let _: End = list![];
+	let _: List<(), End> = list![(),];
+}
+
+#[test]
+fn rest() {
+	let tail = list![2, 3];
+	let list = list![1, ..tail];
+	assert_eq!(list.rest.rest.head, 3);
+}
+
+#[test]
+fn types() {
+	let list: List![u8, &str, ..List![f64]] = list![1, "two", 3.0];
+	let _: List<u8, List<&str, List<f64, End>>> = list;
+
+	let _: List![] = End;
+}
+
+#[test]
+fn patterns() {
+	let list_pat![a, b, ..rest] = list![1, "two", 3.0];
+	assert_eq!((a, b), (1, "two"));
+	let list_pat![c] = rest;
+	assert_eq!(c, 3.0);
+
+	let description = match list![Some(1), None::<u8>] {
+		list_pat![None, ..] => "none first",
+		list_pat![Some(_), Some(_)] => "both",
+		list_pat![Some(_), None] => "some first",
+	};
+	assert_eq!(description, "some first");
+}
+
+mod custom {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct End,
+
+		/// Builder functionality for [`BoxList`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom() {
+	let list: List![use custom::BoxList, custom::End; u8, &str] =
+		list![use custom::BoxList, custom::End; Box::new(1), Box::new("two")];
+
+	let list_pat![use custom::BoxList, custom::End; a, b] = list;
+	assert_eq!((*a, *b), (1, "two"));
+}
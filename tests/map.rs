@@ -0,0 +1,59 @@
+use geode::{list, Func, List, Map, MapMut, MapRef};
+
+struct Widen;
+impl Func<u8> for Widen {
+	type Output = u16;
+	fn call(&mut self, input: u8) -> u16 {
+		u16::from(input) * 1000
+	}
+}
+impl Func<String> for Widen {
+	type Output = usize;
+	fn call(&mut self, input: String) -> usize {
+		input.len()
+	}
+}
+
+#[test]
+fn map() {
+	let list: List![u16, usize] = list![1_u8, String::from("two")].map(Widen);
+	assert_eq!((list.head, list.rest.head), (1000, 3));
+}
+
+struct Describe(Vec<&'static str>);
+impl<'a> Func<&'a u8> for &mut Describe {
+	type Output = &'a u8;
+	fn call(&mut self, input: &'a u8) -> &'a u8 {
+		self.0.push("u8");
+		input
+	}
+}
+impl<'a> Func<&'a String> for &mut Describe {
+	type Output = &'a str;
+	fn call(&mut self, input: &'a String) -> &'a str {
+		self.0.push("String");
+		input
+	}
+}
+
+#[test]
+fn map_ref() {
+	let list = list![1_u8, String::from("two")];
+
+	let mut describe = Describe(vec![]);
+	let refs: List![&u8, &str] = list.map_ref(&mut describe);
+	assert_eq!((*refs.head, refs.rest.head), (1, "two"));
+	assert_eq!(describe.0, ["u8", "String"]);
+}
+
+#[test]
+fn map_mut() {
+	let mut list = list![1, 2, 3];
+
+	let old: List![i32, i32, i32] = list.map_mut(|item: &mut i32| {
+		*item *= 2;
+		*item / 2
+	});
+	assert_eq!((old.head, old.rest.head, old.rest.rest.head), (1, 2, 3));
+	assert_eq!((list.head, list.rest.head, list.rest.rest.head), (2, 4, 6));
+}
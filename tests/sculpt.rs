@@ -0,0 +1,22 @@
+use geode::{list, End, IntoTuple, List, Sculptor};
+
+#[test]
+fn reorder() {
+	let (sculpted, rest) = list![1_u8, "two", 3.0_f32].sculpt::<List![f32, u8, &str], _>();
+	assert_eq!(sculpted.into_tuple(), (3.0, 1, "two"));
+	assert!(matches!(rest, End));
+}
+
+#[test]
+fn subset() {
+	let (sculpted, rest): (List![char, u8], _) = Sculptor::sculpt(list![1_u8, "two", 'c', true]);
+	assert_eq!(sculpted.into_tuple(), ('c', 1));
+	assert_eq!(rest.into_tuple(), ("two", true));
+}
+
+#[test]
+fn empty() {
+	let (sculpted, rest) = list![1_u8].sculpt::<List![], _>();
+	assert!(matches!(sculpted, End));
+	assert_eq!(rest.head, 1);
+}
@@ -0,0 +1,40 @@
+use geode::{list, Here, List, Selector, There};
+
+struct Position(i32, i32);
+struct Velocity(i32, i32);
+struct Name(&'static str);
+
+#[test]
+fn get() {
+	let mut list = list![Position(0, 0), Velocity(1, 2), Name("ball")];
+
+	assert_eq!(list.get::<Name, _>().0, "ball");
+
+	let velocity = list.get::<Velocity, _>();
+	let (dx, dy) = (velocity.0, velocity.1);
+	let position = list.get_mut::<Position, _>();
+	position.0 += dx;
+	position.1 += dy;
+	assert_eq!((list.head.0, list.head.1), (1, 2));
+}
+
+#[test]
+fn pluck() {
+	let list = list![Position(0, 0), Velocity(1, 2), Name("ball")];
+
+	let (velocity, rest): (Velocity, List![Position, Name]) = list.pluck();
+	assert_eq!((velocity.0, velocity.1), (1, 2));
+
+	let (Name(name), rest) = rest.pluck::<Name, _>();
+	assert_eq!(name, "ball");
+	let (Position(x, y), geode::End) = rest.pluck::<Position, _>();
+	assert_eq!((x, y), (0, 0));
+}
+
+#[test]
+fn explicit_index() {
+	let list = list![1_u8, 2_u8];
+
+	assert_eq!(*Selector::<u8, Here>::get(&list), 1);
+	assert_eq!(*list.get::<u8, There<Here>>(), 2);
+}
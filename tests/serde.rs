@@ -0,0 +1,116 @@
+#![cfg(feature = "serde")]
+
+use geode::{list, End, List};
+
+mod lists {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct BoxEnd,
+
+		/// Builder functionality for [`BoxList`] and [`BoxEnd`].
+		pub trait BoxCons,
+	);
+
+	geode::custom_list!(
+		/// A list of numbers.
+		pub struct NumberList {
+			pub head: u32,
+			..
+		},
+
+		/// The end of a [`NumberList`].
+		pub struct NumberEnd,
+
+		/// Builder functionality for [`NumberList`] and [`NumberEnd`].
+		pub trait NumberCons,
+
+		/// An opaque list of numbers.
+		pub struct Numbers,
+	);
+
+	/// Not (de)serializable.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+	pub struct Token;
+
+	geode::custom_list!(
+		/// A list of tokens, which isn't (de)serializable but still compiles.
+		pub struct TokenList {
+			pub head: Token,
+			..
+		},
+
+		/// The end of a [`TokenList`].
+		pub struct TokenEnd,
+
+		/// Builder functionality for [`TokenList`] and [`TokenEnd`].
+		pub trait TokenCons,
+	);
+}
+use lists::{
+	BoxCons, BoxEnd, BoxList, NumberCons, NumberEnd, NumberList, Numbers, Token, TokenCons,
+	TokenEnd,
+};
+
+#[test]
+fn round_trip() {
+	let list = list![1_u8, "two".to_owned(), 3.5];
+	let json = serde_json::to_string(&list).unwrap();
+	assert_eq!(json, r#"[1,"two",3.5]"#);
+	assert_eq!(
+		serde_json::from_str::<List![u8, String, f64]>(&json).unwrap(),
+		list
+	);
+
+	assert_eq!(serde_json::to_string(&End).unwrap(), "[]");
+	assert_eq!(serde_json::from_str::<End>("[]").unwrap(), End);
+}
+
+#[test]
+fn custom_lists() {
+	let boxed = BoxEnd.cons(Box::new(true)).cons(Box::new('a'));
+	let json = serde_json::to_string(&boxed).unwrap();
+	assert_eq!(json, r#"["a",true]"#);
+	let boxed: BoxList<char, BoxList<bool, BoxEnd>> = serde_json::from_str(&json).unwrap();
+	assert_eq!((*boxed.head, *boxed.rest.head), ('a', true));
+
+	type Opaque = Numbers<NumberList<NumberList<NumberEnd>>>;
+	let numbers: Opaque = serde_json::from_str("[1,2]").unwrap();
+	assert_eq!(serde_json::to_string(&numbers).unwrap(), "[1,2]");
+	assert_eq!(
+		serde_json::to_string(&NumberEnd.cons(2).cons(1)).unwrap(),
+		"[1,2]"
+	);
+}
+
+#[test]
+fn unserializable_items() {
+	let tokens = TokenEnd.cons(Token).cons(Token);
+	assert_eq!(tokens.rest.head, Token);
+}
+
+#[test]
+fn length_errors() {
+	let error = serde_json::from_str::<List![u8, u8, u8]>("[1,2]").unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"invalid length 2, expected a list of length 3 at line 1 column 5"
+	);
+
+	let error = serde_json::from_str::<List![u8, u8]>("[1,2,3,4]").unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"invalid length 4, expected a list of length 2 at line 1 column 9"
+	);
+
+	let error = serde_json::from_str::<List![u8]>("[\"one\"]").unwrap_err();
+	assert_eq!(
+		error.to_string(),
+		"invalid type: string \"one\", expected u8 at line 1 column 6"
+	);
+}
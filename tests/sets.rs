@@ -0,0 +1,71 @@
+use geode::{list, End, IsSubsetOf, List, UnionInto};
+
+#[derive(Debug, PartialEq)]
+struct A(u8);
+#[derive(Debug, PartialEq)]
+struct B(u8);
+#[derive(Debug, PartialEq)]
+struct C(u8);
+#[derive(Debug, PartialEq)]
+struct D(u8);
+
+mod lists {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct BoxEnd,
+
+		/// Builder functionality for [`BoxList`] and [`BoxEnd`].
+		pub trait BoxCons,
+	);
+}
+use lists::{BoxCons, BoxEnd};
+
+fn complement<L: IsSubsetOf<Other, I>, Other, I>() -> core::marker::PhantomData<L::Complement> {
+	core::marker::PhantomData
+}
+
+#[test]
+fn subset() {
+	let _: core::marker::PhantomData<List![B, D]> =
+		complement::<List![C, A], List![A, B, C, D], _>();
+	let _: core::marker::PhantomData<List![A]> = complement::<End, List![A], _>();
+}
+
+#[test]
+fn union() {
+	let union: List![D, C, B, A] = list![A(1), B(1), C(1)].union_into(list![C(2), D(2)]);
+	assert_eq!(union, list![D(2), C(1), B(1), A(1)]);
+
+	let union: List![A] = End.union_into(list![A(2)]);
+	assert_eq!(union, list![A(2)]);
+}
+
+#[test]
+fn intersection() {
+	let intersection: List![C, B] =
+		list![A(1), B(1), C(1)].intersection_into(&list![B(2), C(2), D(2)]);
+	assert_eq!(intersection, list![C(1), B(1)]);
+
+	let intersection: End = list![A(1)].intersection_into(&list![B(2)]);
+	assert_eq!(intersection, End);
+}
+
+#[test]
+fn difference() {
+	let difference: List![C, A] = list![A(1), B(1), C(1)].difference_into(&list![B(2), D(2)]);
+	assert_eq!(difference, list![C(1), A(1)]);
+}
+
+#[test]
+fn custom_lists() {
+	let boxed = BoxEnd.cons(Box::new(B(1))).cons(Box::new(A(1)));
+
+	let union: List![Box<A>, Box<B>, Box<C>] = boxed.union_into(BoxEnd.cons(Box::new(C(2))));
+	assert_eq!(*union.rest.rest.head, C(2));
+}
@@ -0,0 +1,109 @@
+use core::{
+	borrow::{Borrow, BorrowMut},
+	fmt::Display,
+};
+use geode::{Cons, End, List, StaticIntoIter, StaticIter, StaticIterMut};
+use std::rc::Rc;
+
+#[test]
+fn homogeneous() {
+	let mut list = End.cons(3).cons(2).cons(1);
+
+	let mut seen = vec![];
+	list.for_each_ref(|item: &i32| seen.push(*item));
+	assert_eq!(seen, [1, 2, 3]);
+
+	list.for_each_mut(|item: &mut i32| *item *= 10);
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 60);
+
+	assert_eq!(
+		list.fold(vec![], |mut items, item: i32| {
+			items.push(item);
+			items
+		}),
+		[10, 20, 30]
+	);
+}
+
+#[test]
+fn short_circuit() {
+	let list = End.cons(3).cons(2).cons(1);
+
+	let mut seen = vec![];
+	let result = list.try_for_each_ref(|item: &i32| {
+		seen.push(*item);
+		if *item == 2 {
+			Err(*item)
+		} else {
+			Ok(())
+		}
+	});
+	assert_eq!(result, Err(2));
+	assert_eq!(seen, [1, 2]);
+}
+
+struct Named(&'static str);
+impl Borrow<dyn Display> for Named {
+	fn borrow(&self) -> &(dyn Display + 'static) {
+		&self.0
+	}
+}
+impl BorrowMut<dyn Display> for Named {
+	fn borrow_mut(&mut self) -> &mut (dyn Display + 'static) {
+		&mut self.0
+	}
+}
+
+#[test]
+fn heterogeneous() {
+	let list: List<Named, List<Box<dyn Display>, End>> =
+		End.cons(Box::new(2) as Box<dyn Display>).cons(Named("one"));
+
+	let joined = list.fold_ref(String::new(), |joined, item: &(dyn Display + 'static)| {
+		format!("{}[{}]", joined, item)
+	});
+	assert_eq!(joined, "[one][2]");
+}
+
+#[test]
+fn shared_only() {
+	let list = End.cons(Rc::new(2)).cons(Rc::new(1));
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 3);
+}
+
+#[test]
+fn into() {
+	let list = End.cons(2_u8).cons(1_u16);
+	assert_eq!(list.fold(0_u32, |sum, item: u32| sum + item), 3);
+}
+
+mod boxed {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct End,
+
+		/// Builder functionality for [`BoxList`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom() {
+	use boxed::{BoxList, End};
+
+	let list = BoxList {
+		head: Box::new(1),
+		rest: BoxList {
+			head: Box::new(2),
+			rest: End,
+		},
+	};
+
+	assert_eq!(list.fold_ref(0, |sum, item: &i32| sum + item), 3);
+}
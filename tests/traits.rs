@@ -0,0 +1,138 @@
+use core::hash::{Hash, Hasher};
+use geode::{list, End, List};
+use std::collections::hash_map::DefaultHasher;
+
+fn hash(value: &impl Hash) -> u64 {
+	let mut hasher = DefaultHasher::new();
+	value.hash(&mut hasher);
+	hasher.finish()
+}
+
+#[test]
+fn debug() {
+	assert_eq!(format!("{:?}", list![1, "two", '3']), r#"[1, "two", '3']"#);
+	assert_eq!(format!("{:?}", End), "[]");
+	assert_eq!(format!("{:?}", list![list![1], End]), "[[1], []]");
+	assert_eq!(format!("{:#?}", list![1]), "List<i32, End> [\n    1,\n]");
+}
+
+#[test]
+fn clone_copy() {
+	let list = list![1, "two"];
+	let copy = list;
+	assert_eq!(list, copy);
+
+	let owned = list![String::from("one")];
+	assert_eq!(owned.clone(), owned);
+}
+
+#[test]
+fn ordering() {
+	assert!(list![1, 2] < list![1, 3]);
+	assert!(list![1, 3] > list![0, 4]);
+	assert_eq!(
+		list![1, "a"].cmp(&list![1, "a"]),
+		core::cmp::Ordering::Equal
+	);
+	assert_eq!(list![f32::NAN].partial_cmp(&list![1.0]), None);
+	assert_ne!(list![1, 2], list![1, 3]);
+}
+
+#[test]
+fn hashing() {
+	assert_eq!(hash(&list![1, "two"]), hash(&list![1, "two"]));
+	assert_eq!(hash(&list![1_u8, 2_u8]), hash(&(1_u8, 2_u8)));
+}
+
+#[test]
+fn default() {
+	let list: List![u8, String, End] = Default::default();
+	assert_eq!(list, list![0, String::new(), End]);
+}
+
+mod boxed {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct End,
+
+		/// Builder functionality for [`BoxList`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom() {
+	use boxed::{BoxList, End};
+
+	let list = BoxList {
+		head: Box::new(1),
+		rest: BoxList {
+			head: Box::new("two"),
+			rest: End,
+		},
+	};
+	assert_eq!(format!("{:?}", list), r#"[1, "two"]"#);
+	assert_eq!(list.clone(), list);
+}
+
+mod manual {
+	/// Not [`Debug`](core::fmt::Debug) or [`Copy`].
+	#[derive(Clone, PartialEq)]
+	pub struct Opaque;
+
+	geode::custom_list!(
+		/// A list of opaque items, without generics.
+		pub struct Opaques {
+			pub head: Opaque,
+			..
+		},
+
+		/// The end of an [`Opaques`].
+		pub struct End,
+
+		/// Builder functionality for [`Opaques`] and [`End`].
+		pub trait Cons,
+	);
+
+	geode::custom_list!(
+		/// A list with its own standard trait implementations.
+		#[no_standard_traits]
+		#[derive(Debug, Clone)]
+		pub struct Derived {
+			pub head: String,
+			..
+		},
+
+		/// The end of a [`Derived`].
+		#[derive(Debug, Clone)]
+		pub struct DerivedEnd,
+
+		/// Builder functionality for [`Derived`] and [`DerivedEnd`].
+		pub trait DerivedCons,
+	);
+}
+
+#[test]
+fn without_generics() {
+	use manual::{Cons, End, Opaque};
+
+	let list = End.cons(Opaque).cons(Opaque);
+	assert!(list.clone() == list);
+}
+
+#[test]
+fn no_standard_traits() {
+	use manual::{DerivedCons, DerivedEnd};
+
+	let list = DerivedEnd.cons("one".to_owned());
+	assert_eq!(
+		format!("{:?}", list.clone()),
+		r#"Derived { head: "one", rest: DerivedEnd }"#
+	);
+}
@@ -0,0 +1,83 @@
+use geode::{list, End, First, Init, Last, List, Reverse, RotateLeft, RotateRight};
+
+#[test]
+fn reverse() {
+	let list: List![f64, &str, u8] = list![1_u8, "two", 3.0].reverse();
+	assert_eq!(
+		(list.head, list.rest.head, list.rest.rest.head),
+		(3.0, "two", 1)
+	);
+
+	let _: End = End.reverse();
+}
+
+#[test]
+fn split_at() {
+	let (prefix, suffix) = list![1_u8, "two", 3.0].split_at::<1>();
+	let prefix: List![u8] = prefix;
+	let suffix: List![&str, f64] = suffix;
+	assert_eq!(prefix.head, 1);
+	assert_eq!(suffix.head, "two");
+
+	let (_, _): (End, List![u8]) = list![1_u8].split_at::<0>();
+	let (_, _): (List![u8], End) = list![1_u8].split_at::<1>();
+}
+
+#[test]
+fn first_last() {
+	let mut list = list![1_u8, "two", 3.0];
+
+	assert_eq!(*list.first(), 1);
+	*list.first_mut() += 1;
+	assert_eq!(*list.last(), 3.0);
+	*list.last_mut() += 1.0;
+	assert_eq!((list.head, list.rest.rest.head), (2, 4.0));
+
+	assert_eq!(*list![()].last(), ());
+}
+
+#[test]
+fn init() {
+	let list: List![u8, &str] = list![1_u8, "two", 3.0].init();
+	assert_eq!(list.rest.head, "two");
+
+	let (init, last): (List![u8], List![&str]) = list.split_last();
+	assert_eq!((init.head, last.head), (1, "two"));
+}
+
+#[test]
+fn rotate() {
+	let left: List![&str, f64, u8] = list![1_u8, "two", 3.0].rotate_left();
+	assert_eq!(left.head, "two");
+
+	let right: List![f64, u8, &str] = list![1_u8, "two", 3.0].rotate_right();
+	assert_eq!(right.head, 3.0);
+
+	let _: End = End.rotate_left().rotate_right();
+	let _: List![u8] = list![1_u8].rotate_left().rotate_right();
+}
+
+mod custom {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct End,
+
+		/// Builder functionality for [`BoxList`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom() {
+	let list = list![use custom::BoxList, custom::End; Box::new(1_u8), Box::new("two")];
+	assert_eq!(**list.last(), "two");
+
+	let rotated: List![use custom::BoxList, custom::End; &str, u8] = list.rotate_right();
+	assert_eq!(*rotated.head, "two");
+}
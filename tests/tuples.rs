@@ -0,0 +1,61 @@
+use geode::{list, AsTupleRefs, End, IntoTuple, List};
+
+#[test]
+fn from_tuple() {
+	let list: List![u8, &str, f64] = (1, "two", 3.0).into();
+	assert_eq!(
+		(list.head, list.rest.head, list.rest.rest.head),
+		(1, "two", 3.0)
+	);
+
+	let _: End = ().into();
+	let _: List![()] = List::from(((),));
+}
+
+#[test]
+fn into_tuple() {
+	assert_eq!(list![1_u8, "two", 3.0].into_tuple(), (1, "two", 3.0));
+	assert_eq!(End.into_tuple(), ());
+}
+
+#[test]
+fn as_tuple_refs() {
+	let list = list![1_u8, String::from("two")];
+	let (one, two) = list.as_tuple_refs();
+	assert_eq!((*one, two.as_str()), (1, "two"));
+}
+
+#[test]
+fn arity_16() {
+	type Sixteen = List![u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8];
+
+	let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+	let list: Sixteen = tuple.into();
+	assert_eq!(*list.at::<15>(), 15);
+	assert_eq!(list.as_tuple_refs().15, &15);
+	assert_eq!(list.into_tuple().15, tuple.15);
+}
+
+mod custom {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct End,
+
+		/// Builder functionality for [`BoxList`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom() {
+	let list: List![use custom::BoxList, custom::End; u8, &str] =
+		(Box::new(1), Box::new("two")).into();
+	let (one, two) = list.into_tuple();
+	assert_eq!((*one, *two), (1, "two"));
+}
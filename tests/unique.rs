@@ -0,0 +1,57 @@
+use geode::{assert_unique_types, list, Contains, List, NotContains, UniqueTypes};
+
+struct Position(i32);
+struct Velocity(i32);
+struct Health;
+
+type Entity = List![Position, Velocity];
+
+assert_unique_types!(geode::End, Entity, List![Health, Position, Velocity]);
+
+mod lists {
+	geode::custom_list!(
+		/// A list of boxed items.
+		pub struct BoxList[T][T0] {
+			pub head: Box<T>,
+			..
+		},
+
+		/// The end of a [`BoxList`].
+		pub struct BoxEnd,
+
+		/// Builder functionality for [`BoxList`] and [`BoxEnd`].
+		pub trait BoxCons,
+	);
+}
+use lists::{BoxCons, BoxEnd, BoxList};
+
+assert_unique_types!(BoxList<u8, BoxList<char, BoxEnd>>);
+
+fn contains<L: Contains<T, I>, T, I>(_: &L) -> bool {
+	true
+}
+
+fn not_contains<L: NotContains<T, I>, T, I>(_: &L) -> bool {
+	true
+}
+
+fn unique<L: UniqueTypes<I>, I>(list: L) -> L {
+	list
+}
+
+#[test]
+fn membership() {
+	let entity: Entity = list![Position(1), Velocity(2)];
+	assert!(contains::<_, Position, _>(&entity));
+	assert!(contains::<_, Velocity, _>(&entity));
+	assert!(not_contains::<_, Health, _>(&entity));
+}
+
+#[test]
+fn generic_uniqueness() {
+	let entity = unique(list![Position(1), Velocity(2)]);
+	assert_eq!(entity.head.0 + entity.rest.head.0, 3);
+
+	let boxed = unique(BoxEnd.cons(Box::new('a')).cons(Box::new(1_u8)));
+	assert_eq!(*boxed.head, 1);
+}
@@ -0,0 +1,59 @@
+use geode::{iterators::Iter, Iteratee, Len, StaticIntoIter, StaticIterMut};
+
+mod layers {
+	mod internal {
+		geode::custom_list!(
+			/// The internal shape of a [`Layers`] list.
+			pub struct LayerList[T][T0] {
+				pub head: Box<T>,
+				..
+			},
+
+			/// The end of a [`LayerList`].
+			pub struct LayerEnd,
+
+			/// Builder functionality for [`Layers`].
+			pub trait LayerCons,
+
+			/// A list of layers that doesn't expose its shape.
+			pub struct Layers,
+		);
+	}
+
+	pub use internal::{LayerCons, Layers};
+}
+
+use layers::{LayerCons, Layers};
+
+#[test]
+fn opaque() {
+	let mut layers = Layers::new()
+		.cons(Box::new(2))
+		.cons(Box::new(1))
+		.r_cons(Box::new(3));
+	assert_eq!(layers.len(), 3);
+
+	layers.for_each_mut(|layer: &mut i32| *layer *= 10);
+	assert_eq!(
+		Iter::new(&layers as &dyn Iteratee<i32>)
+			.copied()
+			.collect::<Vec<_>>(),
+		[10, 20, 30]
+	);
+	assert_eq!(
+		layers.clone().fold(0, |sum, layer: Box<i32>| sum + *layer),
+		60
+	);
+	assert_eq!(format!("{:?}", layers), "Layers([10, 20, 30])");
+	assert_eq!(
+		format!("{:#?}", layers),
+		"Layers(\n    [\n        10,\n        20,\n        30,\n    ],\n)"
+	);
+}
+
+#[test]
+fn empty() {
+	let layers = Layers::default();
+	assert!(layers.is_empty());
+	assert_eq!(layers, Layers::new());
+}
@@ -0,0 +1,58 @@
+use geode::{list, End, Func, IntoTuple, List, Unzip, Zip, ZipWith};
+
+#[test]
+fn zip() {
+	let zipped: List![(u8, &str), (char, bool)] = list![1_u8, 'a'].zip(list!["one", true]);
+	assert_eq!(zipped.head, (1, "one"));
+	assert_eq!(zipped.rest.head, ('a', true));
+
+	assert!(matches!(End.zip(End), End));
+}
+
+#[test]
+fn unzip() {
+	let (left, right) = list![(1_u8, "one"), ('a', true)].unzip();
+	assert_eq!(left.into_tuple(), (1, 'a'));
+	assert_eq!(right.into_tuple(), ("one", true));
+}
+
+struct Add;
+impl<A: Into<u32>, B: Into<u32>> Func<(A, B)> for Add {
+	type Output = u32;
+	fn call(&mut self, (a, b): (A, B)) -> u32 {
+		a.into() + b.into()
+	}
+}
+
+#[test]
+fn zip_with() {
+	let sums = list![1_u8, 2_u16].zip_with(list![10_u16, 20_u8], Add);
+	assert_eq!(sums.into_tuple(), (11, 22));
+}
+
+mod custom {
+	geode::custom_list!(
+		/// A list of words.
+		pub struct Words {
+			pub head: &'static str,
+			..
+		},
+
+		/// The end of a [`Words`].
+		pub struct End,
+
+		/// Builder functionality for [`Words`] and [`End`].
+		pub trait Cons,
+	);
+}
+
+#[test]
+fn custom_without_generics() {
+	use custom::Cons;
+
+	let words = || custom::End.cons("two").cons("one");
+	let zipped = words().zip(words());
+	let (left, right) = zipped.unzip();
+	assert_eq!(left.into_tuple(), ("one", "two"));
+	assert_eq!(right.into_tuple(), ("one", "two"));
+}